@@ -0,0 +1,267 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum Suit {
+    Hearts,
+    Bells,
+    Acorns,
+    Leaves
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum Rank {
+    Seven,
+    Eight,
+    Nine,
+    Ten,
+    Unter,
+    Ober,
+    King,
+    Ace
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Card {
+    pub suit: Suit,
+    pub rank: Rank
+}
+
+impl Card {
+    pub fn new(suit: Suit, rank: Rank) -> Card {
+        Card {suit, rank}
+    }
+    
+    pub fn suit(&self) -> Suit {
+        self.suit
+    }
+
+    pub fn rank(&self) -> Rank {
+        self.rank
+    }
+}
+
+// The compact notation is the suit letter followed by the rank letter, e.g.
+// "HA" for the Ace of Hearts or "LK" for the King of Leaves. The suits are
+// H (Hearts), B (Bells), A (Acorns) and L (Leaves), the ranks U (Unter),
+// O (Ober), K (King), T (Ten), A (Ace) and 7, 8, 9.
+//
+// Parsing is case-insensitive and also accepts "10" for the Ten, the Unicode
+// suit symbols (♥ ♦ ♣ ♠) in place of the suit letter, and the English or
+// German names separated by a space, e.g. "Hearts Ace" or "Herz Ass".
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseCardError {
+    Empty,
+    UnknownSuit(String),
+    UnknownRank(String),
+    // A card in a list could not be parsed. The index starts at 0.
+    InList(usize, Box<ParseCardError>)
+}
+
+impl fmt::Display for ParseCardError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ParseCardError::Empty => write!(f, "empty card"),
+            ParseCardError::UnknownSuit(ref suit)
+                => write!(f, "unknown suit \"{}\"", suit),
+            ParseCardError::UnknownRank(ref rank)
+                => write!(f, "unknown rank \"{}\"", rank),
+            ParseCardError::InList(index, ref error)
+                => write!(f, "card {}: {}", index + 1, error)
+        }
+    }
+}
+
+impl Error for ParseCardError {}
+
+impl Suit {
+    pub fn letter(self) -> char {
+        match self {
+            Suit::Hearts => 'H',
+            Suit::Bells => 'B',
+            Suit::Acorns => 'A',
+            Suit::Leaves => 'L'
+        }
+    }
+
+    pub fn symbol(self) -> char {
+        match self {
+            Suit::Hearts => '♥',
+            Suit::Bells => '♦',
+            Suit::Acorns => '♣',
+            Suit::Leaves => '♠'
+        }
+    }
+
+    pub fn german_name(self) -> &'static str {
+        match self {
+            Suit::Hearts => "Herz",
+            Suit::Bells => "Schelln",
+            Suit::Acorns => "Eichel",
+            Suit::Leaves => "Laub"
+        }
+    }
+}
+
+impl Rank {
+    pub fn letter(self) -> char {
+        match self {
+            Rank::Seven => '7',
+            Rank::Eight => '8',
+            Rank::Nine => '9',
+            Rank::Ten => 'T',
+            Rank::Unter => 'U',
+            Rank::Ober => 'O',
+            Rank::King => 'K',
+            Rank::Ace => 'A'
+        }
+    }
+
+    pub fn german_name(self) -> &'static str {
+        match self {
+            Rank::Seven => "Siebener",
+            Rank::Eight => "Achter",
+            Rank::Nine => "Neuner",
+            Rank::Ten => "Zehner",
+            Rank::Unter => "Unter",
+            Rank::Ober => "Ober",
+            Rank::King => "König",
+            Rank::Ace => "Ass"
+        }
+    }
+}
+
+impl Card {
+    // The suit symbol followed by the rank letter, e.g. "♥A".
+    pub fn to_unicode(self) -> String {
+        format!("{}{}", self.suit.symbol(), self.rank.letter())
+    }
+
+    // E.g. "Herz Ass".
+    pub fn german_name(self) -> String {
+        format!("{} {}", self.suit.german_name(), self.rank.german_name())
+    }
+}
+
+impl fmt::Display for Suit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.letter())
+    }
+}
+
+impl fmt::Display for Rank {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.letter())
+    }
+}
+
+impl fmt::Display for Card {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}{}", self.suit, self.rank)
+    }
+}
+
+const SUIT_NAMES: [(Suit, &str, &str); 4] = [
+    (Suit::Hearts, "hearts", "herz"),
+    (Suit::Bells, "bells", "schelln"),
+    (Suit::Acorns, "acorns", "eichel"),
+    (Suit::Leaves, "leaves", "laub")
+];
+
+const RANK_NAMES: [(Rank, &str, &str); 8] = [
+    (Rank::Seven, "seven", "siebener"),
+    (Rank::Eight, "eight", "achter"),
+    (Rank::Nine, "nine", "neuner"),
+    (Rank::Ten, "ten", "zehner"),
+    (Rank::Unter, "unter", "unter"),
+    (Rank::Ober, "ober", "ober"),
+    (Rank::King, "king", "könig"),
+    (Rank::Ace, "ace", "ass")
+];
+
+impl FromStr for Suit {
+    type Err = ParseCardError;
+
+    fn from_str(s: &str) -> Result<Suit, ParseCardError> {
+        let lower = s.trim().to_lowercase();
+        if lower.is_empty() {
+            return Err(ParseCardError::Empty);
+        }
+
+        SUIT_NAMES.iter()
+            .find(|&&(suit, english, german)| {
+                lower == english || lower == german
+                    || lower == suit.letter().to_lowercase().to_string()
+                    || lower == suit.symbol().to_string()
+            })
+            .map(|&(suit, _, _)| suit)
+            .ok_or_else(|| ParseCardError::UnknownSuit(s.trim().to_string()))
+    }
+}
+
+impl FromStr for Rank {
+    type Err = ParseCardError;
+
+    fn from_str(s: &str) -> Result<Rank, ParseCardError> {
+        let lower = s.trim().to_lowercase();
+        if lower.is_empty() {
+            return Err(ParseCardError::Empty);
+        }
+
+        if lower == "10" {
+            return Ok(Rank::Ten);
+        }
+
+        RANK_NAMES.iter()
+            .find(|&&(rank, english, german)| {
+                lower == english || lower == german
+                    || lower == rank.letter().to_lowercase().to_string()
+            })
+            .map(|&(rank, _, _)| rank)
+            .ok_or_else(|| ParseCardError::UnknownRank(s.trim().to_string()))
+    }
+}
+
+impl FromStr for Card {
+    type Err = ParseCardError;
+
+    fn from_str(s: &str) -> Result<Card, ParseCardError> {
+        let s = s.trim();
+        let words: Vec<&str> = s.split_whitespace().collect();
+
+        match words.len() {
+            0 => Err(ParseCardError::Empty),
+            1 => {
+                let suit_length = s.chars().next().unwrap().len_utf8();
+                let (suit, rank) = s.split_at(suit_length);
+                Ok(Card::new(suit.parse()?, rank.parse()?))
+            },
+            2 => Ok(Card::new(words[0].parse()?, words[1].parse()?)),
+            _ => Err(ParseCardError::UnknownRank(words[1..].join(" ")))
+        }
+    }
+}
+
+// Parses a list of cards in compact notation separated by whitespace or
+// commas, e.g. "HA LK, B10".
+pub fn parse_cards(s: &str) -> Result<Vec<Card>, ParseCardError> {
+    s.split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|token| !token.is_empty())
+        .enumerate()
+        .map(|(index, token)| token.parse().map_err(
+            |error| ParseCardError::InList(index, Box::new(error))))
+        .collect()
+}
+
+// The cards in compact notation separated by spaces.
+pub fn format_cards(cards: &[Card]) -> String {
+    cards.iter()
+        .map(|card| card.to_string())
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,77 @@
+use super::*;
+
+#[test]
+fn test_display_card() {
+    assert_eq!("HA", Card::new(Suit::Hearts, Rank::Ace).to_string());
+    assert_eq!("LK", Card::new(Suit::Leaves, Rank::King).to_string());
+    assert_eq!("BT", Card::new(Suit::Bells, Rank::Ten).to_string());
+    assert_eq!("AU", Card::new(Suit::Acorns, Rank::Unter).to_string());
+    assert_eq!("♥O", Card::new(Suit::Hearts, Rank::Ober).to_unicode());
+    assert_eq!("Eichel König",
+               Card::new(Suit::Acorns, Rank::King).german_name());
+}
+
+#[test]
+fn test_parse_card_compact() {
+    assert_eq!(Ok(Card::new(Suit::Hearts, Rank::Ace)), "HA".parse());
+    assert_eq!(Ok(Card::new(Suit::Acorns, Rank::Ace)), "aa".parse());
+    assert_eq!(Ok(Card::new(Suit::Bells, Rank::Ten)), "B10".parse());
+    assert_eq!(Ok(Card::new(Suit::Leaves, Rank::Seven)), " L7 ".parse());
+    assert_eq!(Ok(Card::new(Suit::Leaves, Rank::Unter)), "♠U".parse());
+}
+
+#[test]
+fn test_parse_card_names() {
+    assert_eq!(Ok(Card::new(Suit::Hearts, Rank::Ace)), "Hearts Ace".parse());
+    assert_eq!(Ok(Card::new(Suit::Hearts, Rank::Ace)), "Herz Ass".parse());
+    assert_eq!(Ok(Card::new(Suit::Bells, Rank::King)),
+               "schelln könig".parse());
+    assert_eq!(Ok(Card::new(Suit::Acorns, Rank::Ten)), "Eichel 10".parse());
+}
+
+#[test]
+fn test_parse_card_round_trip() {
+    let suits = [Suit::Hearts, Suit::Bells, Suit::Acorns, Suit::Leaves];
+
+    for &suit in suits.iter() {
+        for &rank in [Rank::Seven, Rank::Eight, Rank::Nine, Rank::Ten,
+                      Rank::Unter, Rank::Ober, Rank::King, Rank::Ace].iter() {
+            let card = Card::new(suit, rank);
+
+            assert_eq!(Ok(card), card.to_string().parse());
+            assert_eq!(Ok(card), card.to_unicode().parse());
+            assert_eq!(Ok(card), card.german_name().parse());
+        }
+    }
+}
+
+#[test]
+fn test_parse_card_errors() {
+    assert_eq!(Err(ParseCardError::Empty), "".parse::<Card>());
+    assert_eq!(Err(ParseCardError::Empty), "H".parse::<Card>());
+    assert_eq!(Err(ParseCardError::UnknownSuit("X".to_string())),
+               "XA".parse::<Card>());
+    assert_eq!(Err(ParseCardError::UnknownRank("Z".to_string())),
+               "HZ".parse::<Card>());
+    assert_eq!(Err(ParseCardError::UnknownRank("Ace Ace".to_string())),
+               "Hearts Ace Ace".parse::<Card>());
+    assert_eq!("unknown suit \"X\"",
+               "XA".parse::<Card>().unwrap_err().to_string());
+}
+
+#[test]
+fn test_parse_cards() {
+    let cards = vec![Card::new(Suit::Hearts, Rank::Ace),
+                     Card::new(Suit::Leaves, Rank::King),
+                     Card::new(Suit::Bells, Rank::Ten)];
+
+    assert_eq!(Ok(cards.clone()), parse_cards("HA LK, B10"));
+    assert_eq!(Ok(cards.clone()), parse_cards(&format_cards(&cards)));
+    assert_eq!(Ok(Vec::new()), parse_cards("  "));
+
+    let error = parse_cards("HA LX").unwrap_err();
+    let expected = ParseCardError::InList(
+        1, Box::new(ParseCardError::UnknownRank("X".to_string())));
+    assert_eq!(expected, error);
+    assert_eq!("card 2: unknown rank \"X\"", error.to_string());
+}
@@ -0,0 +1,40 @@
+use rand::Rng;
+use rand::isaac::Isaac64Rng;
+
+use schnapsen::seeded_rng;
+use schnapsen::moves::Move;
+use schnapsen::player_view::PlayerView;
+
+// A decision-maker for one seat. It only sees what the player is entitled to
+// know and has to return one of the legal moves, which are never empty.
+pub trait Agent {
+    fn choose_move(&mut self, view: &PlayerView, legal_moves: &[Move]) -> Move;
+}
+
+// Plays a uniformly random legal move.
+pub struct RandomAgent {
+    rng: Isaac64Rng
+}
+
+impl RandomAgent {
+    pub fn new() -> RandomAgent {
+        RandomAgent {rng: rand::thread_rng().gen()}
+    }
+
+    pub fn new_seeded(seed: u64) -> RandomAgent {
+        RandomAgent {rng: seeded_rng(seed)}
+    }
+}
+
+impl Default for RandomAgent {
+    fn default() -> RandomAgent {
+        RandomAgent::new()
+    }
+}
+
+impl Agent for RandomAgent {
+    fn choose_move(&mut self, _view: &PlayerView, legal_moves: &[Move])
+                   -> Move {
+        legal_moves[self.rng.gen_range(0, legal_moves.len())]
+    }
+}
@@ -0,0 +1,399 @@
+use std::f64;
+use std::fmt;
+
+use schnapsen::generate_seeded_deck;
+use schnapsen::agent::Agent;
+use schnapsen::deal_result::DealResult;
+use schnapsen::game::Game;
+use schnapsen::player::PlayerId;
+use schnapsen::runner::{run_deal, IllegalMove};
+
+// Creates a fresh agent for a deal from the given seed.
+pub type AgentFactory = Box<dyn Fn(u64) -> Box<dyn Agent>>;
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum ArenaMode {
+    // Every seeded deal is played once, alternating the seats from deal to
+    // deal.
+    Independent,
+    // Every seeded deal is played twice with the same deck, once from each
+    // seat, and scored as the difference of the two results.
+    Duplicate
+}
+
+// A round-robin tournament: every pair of registered agents plays the same
+// seeded deals.
+pub struct Arena {
+    entries: Vec<(String, AgentFactory)>,
+    deals_per_pairing: u32,
+    seed: u64,
+    mode: ArenaMode
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct ArenaError {
+    pub agent: String,
+    pub deal_seed: u64,
+    pub illegal_move: IllegalMove
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Standing {
+    pub name: String,
+    pub deals: u32,
+    pub wins: u32,
+    pub game_points_won: u32,
+    pub game_points_lost: u32,
+    // The boards played in duplicate mode and the sum of their scores, see
+    // Pairing.
+    pub boards: u32,
+    pub duplicate_score: i32,
+    // Elo rating with the mean of all agents at 0.
+    pub rating: f64,
+    // Half the width of the 95% confidence interval of the rating.
+    pub rating_error: f64
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Pairing {
+    pub first: String,
+    pub second: String,
+    pub deals: u32,
+    pub first_wins: u32,
+    pub first_game_points: u32,
+    pub second_game_points: u32,
+    // A board is a deck played from both seats. Its score is the net game
+    // points of the first agent over both deals.
+    pub boards: u32,
+    pub duplicate_score: i32
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct ArenaReport {
+    // Sorted by rating, best first.
+    pub standings: Vec<Standing>,
+    pub pairings: Vec<Pairing>
+}
+
+impl Arena {
+    pub fn new(deals_per_pairing: u32, seed: u64) -> Arena {
+        Arena {entries: Vec::new(), deals_per_pairing, seed,
+               mode: ArenaMode::Independent}
+    }
+
+    // In duplicate mode every pairing plays twice as many deals.
+    pub fn set_mode(&mut self, mode: ArenaMode) {
+        self.mode = mode;
+    }
+
+    pub fn mode(&self) -> ArenaMode {
+        self.mode
+    }
+
+    pub fn register<F>(&mut self, name: &str, factory: F)
+        where F: Fn(u64) -> Box<dyn Agent> + 'static {
+        self.entries.push((name.to_string(), Box::new(factory)));
+    }
+
+    pub fn run(&self) -> Result<ArenaReport, ArenaError> {
+        let mut pairings = Vec::new();
+
+        for first in 0..self.entries.len() {
+            for second in first + 1..self.entries.len() {
+                pairings.push(self.play_pairing(first, second)?);
+            }
+        }
+
+        Ok(ArenaReport::new(&self.names(), pairings))
+    }
+
+    fn names(&self) -> Vec<String> {
+        self.entries.iter().map(|entry| entry.0.clone()).collect()
+    }
+
+    fn play_pairing(&self, first: usize, second: usize)
+                    -> Result<Pairing, ArenaError> {
+        let mut pairing = Pairing {
+            first: self.entries[first].0.clone(),
+            second: self.entries[second].0.clone(),
+            deals: 0, first_wins: 0, first_game_points: 0,
+            second_game_points: 0, boards: 0, duplicate_score: 0
+        };
+
+        for deal in 0..self.deals_per_pairing {
+            let deal_seed = self.seed.wrapping_add(u64::from(deal));
+
+            match self.mode {
+                ArenaMode::Independent => {
+                    let first_seat = if deal % 2 == 0 {
+                        PlayerId::Player1
+                    } else {
+                        PlayerId::Player2
+                    };
+
+                    let result = self.play_deal(first, second, deal_seed,
+                                                first_seat)?;
+                    pairing.add(&result, first_seat);
+                },
+                ArenaMode::Duplicate => {
+                    let mut score = 0;
+
+                    for &first_seat in [PlayerId::Player1,
+                                        PlayerId::Player2].iter() {
+                        let result = self.play_deal(first, second, deal_seed,
+                                                    first_seat)?;
+                        score += pairing.add(&result, first_seat);
+                    }
+
+                    pairing.boards += 1;
+                    pairing.duplicate_score += score;
+                }
+            }
+        }
+
+        Ok(pairing)
+    }
+
+    fn play_deal(&self, first: usize, second: usize, deal_seed: u64,
+                 first_seat: PlayerId) -> Result<DealResult, ArenaError> {
+        let (player1, player2) = match first_seat {
+            PlayerId::Player1 => (first, second),
+            PlayerId::Player2 => (second, first)
+        };
+
+        // The agents get the same seeds from both seats.
+        let agent_seed = |index| if index == first {
+            deal_seed.wrapping_mul(2)
+        } else {
+            deal_seed.wrapping_mul(2).wrapping_add(1)
+        };
+        let mut agent1 = (self.entries[player1].1)(agent_seed(player1));
+        let mut agent2 = (self.entries[player2].1)(agent_seed(player2));
+
+        let deck = generate_seeded_deck(deal_seed);
+        let mut game = Game::from_deck(deck)
+            .expect("a generated deck should be valid");
+        let record = run_deal(&mut game, &mut *agent1, &mut *agent2)
+            .map_err(|illegal_move| {
+                let index = match illegal_move.player {
+                    PlayerId::Player1 => player1,
+                    PlayerId::Player2 => player2
+                };
+
+                ArenaError {agent: self.entries[index].0.clone(), deal_seed,
+                            illegal_move}
+            })?;
+
+        Ok(record.result)
+    }
+}
+
+impl Pairing {
+    // Returns the net game points of the first agent.
+    fn add(&mut self, result: &DealResult, first_seat: PlayerId) -> i32 {
+        self.deals += 1;
+
+        if result.winner == first_seat {
+            self.first_wins += 1;
+            self.first_game_points += result.game_points;
+            result.game_points as i32
+        } else {
+            self.second_game_points += result.game_points;
+            -(result.game_points as i32)
+        }
+    }
+
+    pub fn second_wins(&self) -> u32 {
+        self.deals - self.first_wins
+    }
+
+    pub fn average_duplicate_score(&self) -> f64 {
+        if self.boards == 0 {
+            0.0
+        } else {
+            f64::from(self.duplicate_score) / f64::from(self.boards)
+        }
+    }
+}
+
+impl Standing {
+    fn add(&mut self, pairing: &Pairing, is_first: bool) {
+        self.deals += pairing.deals;
+        self.boards += pairing.boards;
+
+        if is_first {
+            self.wins += pairing.first_wins;
+            self.game_points_won += pairing.first_game_points;
+            self.game_points_lost += pairing.second_game_points;
+            self.duplicate_score += pairing.duplicate_score;
+        } else {
+            self.wins += pairing.second_wins();
+            self.game_points_won += pairing.second_game_points;
+            self.game_points_lost += pairing.first_game_points;
+            self.duplicate_score -= pairing.duplicate_score;
+        }
+    }
+
+    pub fn win_rate(&self) -> f64 {
+        if self.deals == 0 {
+            0.0
+        } else {
+            f64::from(self.wins) / f64::from(self.deals)
+        }
+    }
+
+    // The net game points per deal.
+    pub fn average_game_points(&self) -> f64 {
+        if self.deals == 0 {
+            0.0
+        } else {
+            (f64::from(self.game_points_won) - f64::from(self.game_points_lost))
+                / f64::from(self.deals)
+        }
+    }
+
+    pub fn average_duplicate_score(&self) -> f64 {
+        if self.boards == 0 {
+            0.0
+        } else {
+            f64::from(self.duplicate_score) / f64::from(self.boards)
+        }
+    }
+}
+
+impl ArenaReport {
+    fn new(names: &[String], pairings: Vec<Pairing>) -> ArenaReport {
+        let index = |name: &String| {
+            names.iter().position(|other| other == name).unwrap()
+        };
+
+        let mut standings: Vec<Standing> = names.iter()
+            .map(|name| Standing {name: name.clone(), deals: 0, wins: 0,
+                                  game_points_won: 0, game_points_lost: 0,
+                                  boards: 0, duplicate_score: 0, rating: 0.0,
+                                  rating_error: 0.0})
+            .collect();
+
+        let mut games = vec![vec![0.0; names.len()]; names.len()];
+        let mut wins = vec![vec![0.0; names.len()]; names.len()];
+
+        for pairing in &pairings {
+            let first = index(&pairing.first);
+            let second = index(&pairing.second);
+
+            standings[first].add(pairing, true);
+            standings[second].add(pairing, false);
+
+            games[first][second] = f64::from(pairing.deals);
+            games[second][first] = f64::from(pairing.deals);
+            wins[first][second] = f64::from(pairing.first_wins);
+            wins[second][first] = f64::from(pairing.second_wins());
+        }
+
+        let (ratings, errors) = elo_ratings(&games, &wins);
+        for (standing, (rating, error)) in standings.iter_mut()
+            .zip(ratings.into_iter().zip(errors)) {
+            standing.rating = rating;
+            standing.rating_error = error;
+        }
+
+        standings.sort_by(|a, b| b.rating.partial_cmp(&a.rating).unwrap());
+
+        ArenaReport {standings, pairings}
+    }
+}
+
+impl fmt::Display for ArenaReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let duplicate = self.standings.iter()
+            .any(|standing| standing.boards > 0);
+
+        write!(f, "{:<20} {:>6} {:>8} {:>9} {:>14}",
+               "Agent", "Deals", "Win %", "Points", "Elo")?;
+        if duplicate {
+            write!(f, " {:>9}", "Duplicate")?;
+        }
+        writeln!(f)?;
+
+        for standing in &self.standings {
+            write!(f, "{:<20} {:>6} {:>7.1}% {:>+9.2} {:>+7.0} ± {:<4.0}",
+                   standing.name, standing.deals, standing.win_rate() * 100.0,
+                   standing.average_game_points(), standing.rating,
+                   standing.rating_error)?;
+            if duplicate {
+                write!(f, " {:>+9.2}", standing.average_duplicate_score())?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+const ELO_SCALE: f64 = 400.0 / f64::consts::LN_10;
+
+// Bradley-Terry ratings on the Elo scale, fitted with the MM algorithm.
+// Every pairing gets half a virtual win for both sides so that agents that
+// win or lose everything still get finite ratings. The errors are derived
+// from the Fisher information of each rating.
+fn elo_ratings(games: &[Vec<f64>], wins: &[Vec<f64>]) -> (Vec<f64>, Vec<f64>) {
+    let count = games.len();
+    let prior = |i: usize, j: usize| if games[i][j] > 0.0 { 0.5 } else { 0.0 };
+
+    let mut strengths = vec![1.0; count];
+
+    for _ in 0..1000 {
+        let mut next = strengths.clone();
+
+        for i in 0..count {
+            let total_wins: f64 = (0..count).map(|j| wins[i][j] + prior(i, j))
+                .sum();
+            let denominator: f64 = (0..count)
+                .filter(|&j| j != i)
+                .map(|j| (games[i][j] + 2.0 * prior(i, j))
+                     / (strengths[i] + strengths[j]))
+                .sum();
+
+            if denominator > 0.0 {
+                next[i] = total_wins / denominator;
+            }
+        }
+
+        let log_mean = next.iter().map(|s: &f64| s.ln()).sum::<f64>()
+            / count as f64;
+        for strength in &mut next {
+            *strength /= log_mean.exp();
+        }
+
+        let change = next.iter().zip(&strengths)
+            .map(|(a, b)| (a - b).abs())
+            .fold(0.0, f64::max);
+        strengths = next;
+
+        if change < 1e-9 {
+            break;
+        }
+    }
+
+    let ratings = strengths.iter().map(|s| s.ln() * ELO_SCALE).collect();
+    let errors = (0..count).map(|i| {
+        let information: f64 = (0..count)
+            .filter(|&j| j != i)
+            .map(|j| {
+                let p = strengths[i] / (strengths[i] + strengths[j]);
+                games[i][j] * p * (1.0 - p)
+            })
+            .sum();
+
+        if information > 0.0 {
+            1.96 * ELO_SCALE / information.sqrt()
+        } else {
+            f64::INFINITY
+        }
+    }).collect();
+
+    (ratings, errors)
+}
+
+#[cfg(test)]
+mod tests;
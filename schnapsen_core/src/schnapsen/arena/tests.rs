@@ -0,0 +1,151 @@
+use super::*;
+use schnapsen::agent::RandomAgent;
+use schnapsen::moves::Move;
+use schnapsen::player_view::PlayerView;
+
+// Always declares a win, which is illegal before reaching 66.
+struct EagerAgent;
+
+impl Agent for EagerAgent {
+    fn choose_move(&mut self, _view: &PlayerView, _legal_moves: &[Move])
+                   -> Move {
+        Move::DeclareWin
+    }
+}
+
+fn random_arena(names: &[&str], deals_per_pairing: u32) -> Arena {
+    let mut arena = Arena::new(deals_per_pairing, 42);
+    for name in names {
+        arena.register(name, |seed| Box::new(RandomAgent::new_seeded(seed)));
+    }
+
+    arena
+}
+
+#[test]
+fn test_arena_two_agents() {
+    let report = random_arena(&["a", "b"], 10).run().unwrap();
+
+    assert_eq!(1, report.pairings.len());
+    assert_eq!(10, report.pairings[0].deals);
+    assert_eq!(2, report.standings.len());
+
+    let total_wins: u32 = report.standings.iter()
+        .map(|standing| standing.wins)
+        .sum();
+    assert_eq!(10, total_wins);
+
+    for standing in &report.standings {
+        assert_eq!(10, standing.deals);
+        assert!(standing.rating_error.is_finite());
+    }
+
+    let rating_sum: f64 = report.standings.iter()
+        .map(|standing| standing.rating)
+        .sum();
+    assert!(rating_sum.abs() < 1e-6);
+    assert!(report.standings[0].rating >= report.standings[1].rating);
+}
+
+#[test]
+fn test_arena_round_robin() {
+    let report = random_arena(&["a", "b", "c"], 4).run().unwrap();
+
+    assert_eq!(3, report.pairings.len());
+    for standing in &report.standings {
+        assert_eq!(8, standing.deals);
+    }
+
+    let text = report.to_string();
+    for name in &["a", "b", "c"] {
+        assert!(text.contains(name));
+    }
+}
+
+#[test]
+fn test_arena_is_reproducible() {
+    let report1 = random_arena(&["a", "b"], 6).run().unwrap();
+    let report2 = random_arena(&["a", "b"], 6).run().unwrap();
+
+    assert_eq!(report1, report2);
+}
+
+#[test]
+fn test_arena_illegal_move() {
+    let mut arena = random_arena(&["random"], 2);
+    arena.register("eager", |_| Box::new(EagerAgent));
+
+    let error = arena.run().unwrap_err();
+    assert_eq!("eager", error.agent);
+    assert_eq!(Move::DeclareWin, error.illegal_move.mv);
+}
+
+#[test]
+fn test_elo_ratings() {
+    let games = vec![vec![0.0, 100.0], vec![100.0, 0.0]];
+
+    let even = vec![vec![0.0, 50.0], vec![50.0, 0.0]];
+    let (ratings, errors) = elo_ratings(&games, &even);
+    assert!(ratings[0].abs() < 1e-6 && ratings[1].abs() < 1e-6);
+    assert!((errors[0] - errors[1]).abs() < 1e-6);
+
+    // 76 percent is roughly 200 Elo points of difference.
+    let uneven = vec![vec![0.0, 76.0], vec![24.0, 0.0]];
+    let (ratings, _) = elo_ratings(&games, &uneven);
+    let difference = ratings[0] - ratings[1];
+    assert!(difference > 180.0 && difference < 210.0);
+
+    let sweep = vec![vec![0.0, 100.0], vec![0.0, 0.0]];
+    let (ratings, errors) = elo_ratings(&games, &sweep);
+    assert!(ratings[0].is_finite() && ratings[0] > 0.0);
+    assert!(errors[0].is_finite());
+}
+
+// Plays the first legal move, so it behaves the same from both seats of the
+// same deck.
+struct FirstMoveAgent;
+
+impl Agent for FirstMoveAgent {
+    fn choose_move(&mut self, _view: &PlayerView, legal_moves: &[Move])
+                   -> Move {
+        legal_moves[0]
+    }
+}
+
+#[test]
+fn test_arena_duplicate_mode() {
+    let mut arena = random_arena(&["a", "b"], 5);
+    arena.set_mode(ArenaMode::Duplicate);
+    let report = arena.run().unwrap();
+
+    let pairing = &report.pairings[0];
+    assert_eq!(10, pairing.deals);
+    assert_eq!(5, pairing.boards);
+    assert_eq!(pairing.first_game_points as i32
+               - pairing.second_game_points as i32,
+               pairing.duplicate_score);
+
+    let scores: Vec<i32> = report.standings.iter()
+        .map(|standing| standing.duplicate_score)
+        .collect();
+    assert_eq!(0, scores[0] + scores[1]);
+    assert!(report.to_string().contains("Duplicate"));
+}
+
+#[test]
+fn test_arena_duplicate_mode_cancels_the_cards() {
+    // Identical agents get the same cards from both seats, so every board
+    // is a draw.
+    let mut arena = Arena::new(8, 3);
+    arena.set_mode(ArenaMode::Duplicate);
+    arena.register("a", |_| Box::new(FirstMoveAgent));
+    arena.register("b", |_| Box::new(FirstMoveAgent));
+
+    let report = arena.run().unwrap();
+    let pairing = &report.pairings[0];
+
+    assert_eq!(8, pairing.boards);
+    assert_eq!(0, pairing.duplicate_score);
+    assert_eq!(pairing.first_wins, pairing.second_wins());
+    assert_eq!(pairing.first_game_points, pairing.second_game_points);
+}
@@ -0,0 +1,227 @@
+use std::time::{Duration, Instant};
+
+use rand::Rng;
+use rand::isaac::Isaac64Rng;
+
+use schnapsen::seeded_rng;
+use schnapsen::agent::Agent;
+use schnapsen::deal_result::DealResult;
+use schnapsen::game::Game;
+use schnapsen::knowledge::sample_game;
+use schnapsen::moves::Move;
+use schnapsen::player::PlayerId;
+use schnapsen::player_view::PlayerView;
+use schnapsen::solver::outcome;
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+pub struct IsmctsConfig {
+    // The maximal number of iterations per decision.
+    pub iterations: u32,
+    // If set, the search also stops when this much time has passed.
+    pub time_limit: Option<Duration>,
+    // The exploration constant of the UCB formula.
+    pub exploration: f64
+}
+
+impl Default for IsmctsConfig {
+    fn default() -> IsmctsConfig {
+        IsmctsConfig {iterations: 1000, time_limit: None, exploration: 0.7}
+    }
+}
+
+// Single-observer information set Monte Carlo tree search. Every iteration
+// samples the hidden cards anew and only walks the branches that are legal in
+// that sample, so the tree is built over what the player can actually know.
+pub struct IsmctsBot {
+    config: IsmctsConfig,
+    rng: Isaac64Rng
+}
+
+#[derive(Clone, Debug)]
+struct Node {
+    // The move leading to this node and the player who made it.
+    mv: Option<Move>,
+    player: Option<PlayerId>,
+    parent: Option<usize>,
+    children: Vec<usize>,
+    visits: u32,
+    // The number of times this node could have been selected.
+    availability: u32,
+    // The sum of the rewards for the player who made the move.
+    reward: f64
+}
+
+impl Node {
+    fn new(mv: Option<Move>, player: Option<PlayerId>, parent: Option<usize>)
+           -> Node {
+        Node {mv, player, parent, children: Vec::new(), visits: 0,
+              availability: 0, reward: 0.0}
+    }
+}
+
+impl IsmctsBot {
+    pub fn new(config: IsmctsConfig) -> IsmctsBot {
+        IsmctsBot {config, rng: rand::thread_rng().gen()}
+    }
+
+    pub fn new_seeded(config: IsmctsConfig, seed: u64) -> IsmctsBot {
+        IsmctsBot {config, rng: seeded_rng(seed)}
+    }
+
+    pub fn config(&self) -> IsmctsConfig {
+        self.config
+    }
+}
+
+impl Agent for IsmctsBot {
+    // Panics if there are no legal moves.
+    fn choose_move(&mut self, view: &PlayerView, legal_moves: &[Move])
+                   -> Move {
+        if legal_moves.len() == 1 || self.config.iterations == 0 {
+            return legal_moves[0];
+        }
+
+        let start = Instant::now();
+        let mut nodes = vec![Node::new(None, None, None)];
+
+        for _ in 0..self.config.iterations {
+            if let Some(limit) = self.config.time_limit {
+                if start.elapsed() >= limit {
+                    break;
+                }
+            }
+
+            let game = sample_game(view, &mut self.rng);
+            self.iterate(&mut nodes, game);
+        }
+
+        let visits = |mv: Move| {
+            nodes[0].children.iter()
+                .map(|&child| &nodes[child])
+                .find(|node| node.mv == Some(mv))
+                .map(|node| node.visits)
+                .unwrap_or(0)
+        };
+
+        let best_index = (0..legal_moves.len())
+            .max_by_key(|&index| (visits(legal_moves[index]),
+                                  -(index as i64)))
+            .unwrap();
+
+        legal_moves[best_index]
+    }
+}
+
+impl IsmctsBot {
+    fn iterate(&mut self, nodes: &mut Vec<Node>, mut game: Game) {
+        let mut current = 0;
+
+        // Selection.
+        loop {
+            if game.is_game_over() {
+                break;
+            }
+
+            let legal_moves = game.legal_moves();
+            let untried: Vec<Move> = legal_moves.iter().cloned()
+                .filter(|&mv| find_child(nodes, current, mv).is_none())
+                .collect();
+
+            if !untried.is_empty() {
+                // Expansion.
+                let mv = untried[self.rng.gen_range(0, untried.len())];
+                let player = game.player_on_turn();
+                apply_legal(&mut game, mv);
+
+                for &legal_move in &legal_moves {
+                    let child = find_child(nodes, current, legal_move);
+                    if let Some(child) = child {
+                        nodes[child].availability += 1;
+                    }
+                }
+
+                let child = nodes.len();
+                nodes.push(Node::new(Some(mv), Some(player), Some(current)));
+                nodes[child].availability += 1;
+                nodes[current].children.push(child);
+                current = child;
+                break;
+            }
+
+            let children: Vec<usize> = legal_moves.iter()
+                .filter_map(|&mv| find_child(nodes, current, mv))
+                .collect();
+
+            for &child in &children {
+                nodes[child].availability += 1;
+            }
+
+            let exploration = self.config.exploration;
+            current = *children.iter()
+                .max_by(|&&a, &&b| {
+                    ucb(&nodes[a], exploration)
+                        .partial_cmp(&ucb(&nodes[b], exploration))
+                        .unwrap()
+                })
+                .unwrap();
+
+            apply_legal(&mut game, nodes[current].mv.unwrap());
+        }
+
+        // Simulation.
+        let result = self.rollout(game);
+
+        // Backpropagation.
+        let mut node = Some(current);
+        while let Some(index) = node {
+            nodes[index].visits += 1;
+            if let Some(player) = nodes[index].player {
+                nodes[index].reward += reward(outcome(&result, player));
+            }
+            node = nodes[index].parent;
+        }
+    }
+
+    // Plays randomly until the deal is over but always declares a win when
+    // possible and never closes.
+    fn rollout(&mut self, mut game: Game) -> DealResult {
+        while !game.is_game_over() {
+            let moves: Vec<Move> = game.legal_moves().into_iter()
+                .filter(|&mv| mv != Move::Close)
+                .collect();
+
+            let declaration = moves.iter().cloned().find(|&mv| matches!(mv,
+                Move::DeclareWin | Move::DeclareTwentyWin(_)
+                    | Move::DeclareFortyWin));
+
+            let mv = declaration.unwrap_or_else(
+                || moves[self.rng.gen_range(0, moves.len())]);
+            apply_legal(&mut game, mv);
+        }
+
+        game.deal_result().unwrap()
+    }
+}
+
+fn find_child(nodes: &[Node], parent: usize, mv: Move) -> Option<usize> {
+    nodes[parent].children.iter().cloned()
+        .find(|&child| nodes[child].mv == Some(mv))
+}
+
+fn ucb(node: &Node, exploration: f64) -> f64 {
+    let visits = f64::from(node.visits);
+    node.reward / visits
+        + exploration * (f64::from(node.availability).ln() / visits).sqrt()
+}
+
+// Maps the game points won or lost to the range from 0 to 1.
+fn reward(game_points: i32) -> f64 {
+    f64::from(game_points + 3) / 6.0
+}
+
+fn apply_legal(game: &mut Game, mv: Move) {
+    game.apply(mv).expect("the move should be legal");
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,96 @@
+use super::*;
+use schnapsen::agent::Agent;
+use schnapsen::game_builder::GameBuilder;
+use cards::{Card, Suit, Rank};
+
+fn fast_config() -> IsmctsConfig {
+    IsmctsConfig {iterations: 50, ..Default::default()}
+}
+
+fn play_out(game: &mut Game, bot1: &mut IsmctsBot, bot2: &mut IsmctsBot) {
+    while !game.is_game_over() {
+        let player = game.player_on_turn();
+        let view = game.view_for(player);
+        let legal_moves = game.legal_moves();
+
+        let bot = match player {
+            PlayerId::Player1 => &mut *bot1,
+            PlayerId::Player2 => &mut *bot2
+        };
+        let mv = bot.choose_move(&view, &legal_moves);
+
+        assert!(legal_moves.contains(&mv));
+        assert!(game.apply(mv).is_ok());
+    }
+}
+
+#[test]
+fn test_ismcts_plays_legal_moves_until_game_over() {
+    for seed in 0..3 {
+        let mut game = Game::new_seeded(seed);
+        let mut bot1 = IsmctsBot::new_seeded(fast_config(), seed);
+        let mut bot2 = IsmctsBot::new_seeded(fast_config(), seed + 100);
+
+        play_out(&mut game, &mut bot1, &mut bot2);
+        assert!(game.deal_result().is_some());
+    }
+}
+
+#[test]
+fn test_ismcts_is_deterministic_with_seed() {
+    let mut game1 = Game::new_seeded(4);
+    let mut game2 = Game::new_seeded(4);
+
+    play_out(&mut game1, &mut IsmctsBot::new_seeded(fast_config(), 1),
+             &mut IsmctsBot::new_seeded(fast_config(), 2));
+    play_out(&mut game2, &mut IsmctsBot::new_seeded(fast_config(), 1),
+             &mut IsmctsBot::new_seeded(fast_config(), 2));
+
+    assert_eq!(game1.events(), game2.events());
+}
+
+#[test]
+fn test_ismcts_time_limit() {
+    let game = Game::new_seeded(3);
+    let view = game.view_for(game.player_on_turn());
+    let config = IsmctsConfig {iterations: u32::MAX,
+                               time_limit: Some(Duration::from_millis(20)),
+                               ..Default::default()};
+    let mut bot = IsmctsBot::new_seeded(config, 1);
+
+    let mv = bot.choose_move(&view, &game.legal_moves());
+    assert!(game.legal_moves().contains(&mv));
+}
+
+#[test]
+fn test_ismcts_declares_win() {
+    let deck = ::schnapsen::generate_deck();
+    let hand1 = vec![Card::new(Suit::Leaves, Rank::Unter),
+                     Card::new(Suit::Bells, Rank::Unter)];
+    let hand2 = vec![Card::new(Suit::Leaves, Rank::Ace),
+                     Card::new(Suit::Bells, Rank::Ace)];
+    let wins1: Vec<Card> = deck.iter().cloned()
+        .filter(|card| card.rank() == Rank::Ten || card.rank() == Rank::King
+                || card.rank() == Rank::Ober)
+        .collect();
+    let wins2: Vec<Card> = deck.iter().cloned()
+        .filter(|card| !wins1.contains(card) && !hand1.contains(card)
+                && !hand2.contains(card))
+        .collect();
+
+    let game = GameBuilder::new()
+        .hand(PlayerId::Player1, hand1)
+        .hand(PlayerId::Player2, hand2)
+        .wins(PlayerId::Player1, wins1)
+        .wins(PlayerId::Player2, wins2)
+        .trump(Suit::Hearts)
+        .build()
+        .unwrap();
+
+    let legal_moves = game.legal_moves();
+    assert!(legal_moves.contains(&Move::DeclareWin));
+
+    let mut bot = IsmctsBot::new_seeded(fast_config(), 1);
+    let view = game.view_for(PlayerId::Player1);
+    assert_eq!(Move::DeclareWin, bot.choose_move(&view, &legal_moves));
+}
@@ -0,0 +1,5 @@
+mod ismcts;
+mod pimc;
+
+pub use self::ismcts::{IsmctsBot, IsmctsConfig};
+pub use self::pimc::{PimcBot, PimcConfig};
@@ -0,0 +1,83 @@
+use rand::Rng;
+use rand::isaac::Isaac64Rng;
+
+use schnapsen::seeded_rng;
+use schnapsen::agent::Agent;
+use schnapsen::knowledge::sample_game;
+use schnapsen::moves::Move;
+use schnapsen::player_view::PlayerView;
+use schnapsen::solver::evaluate;
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct PimcConfig {
+    // The number of sampled deals each move is evaluated on.
+    pub samples: u32,
+    // The number of moves searched in each sample before the position is
+    // rated heuristically. Endgames are always searched to the end.
+    pub depth: u32
+}
+
+impl Default for PimcConfig {
+    fn default() -> PimcConfig {
+        PimcConfig {samples: 20, depth: 2}
+    }
+}
+
+// Perfect-information Monte Carlo: the hidden cards are sampled consistently
+// with what the player knows, each sample is searched as if all cards were
+// visible, and the move with the best average value is chosen.
+pub struct PimcBot {
+    config: PimcConfig,
+    rng: Isaac64Rng
+}
+
+impl PimcBot {
+    pub fn new(config: PimcConfig) -> PimcBot {
+        PimcBot {config, rng: rand::thread_rng().gen()}
+    }
+
+    pub fn new_seeded(config: PimcConfig, seed: u64) -> PimcBot {
+        PimcBot {config, rng: seeded_rng(seed)}
+    }
+
+    pub fn config(&self) -> PimcConfig {
+        self.config
+    }
+}
+
+impl Agent for PimcBot {
+    // Panics if there are no legal moves.
+    fn choose_move(&mut self, view: &PlayerView, legal_moves: &[Move])
+                   -> Move {
+        if legal_moves.len() == 1 || self.config.samples == 0 {
+            return legal_moves[0];
+        }
+
+        let player = view.player_id;
+        let mut totals = vec![0i64; legal_moves.len()];
+
+        for _ in 0..self.config.samples {
+            let game = sample_game(view, &mut self.rng);
+
+            for (total, &mv) in totals.iter_mut().zip(legal_moves) {
+                let mut next = game.clone();
+                *total += match next.apply(mv) {
+                    Ok(()) => i64::from(evaluate(&next, player,
+                                                 self.config.depth)),
+                    // The legal moves do not depend on the hidden cards, so
+                    // this only happens if the caller passes illegal moves.
+                    Err(_) => i64::from(i32::MIN)
+                };
+            }
+        }
+
+        let best_index = (0..legal_moves.len())
+            .max_by_key(|&index| (totals[index], -(index as i64)))
+            .unwrap();
+
+        legal_moves[best_index]
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,90 @@
+use super::*;
+use schnapsen::agent::Agent;
+use schnapsen::game::Game;
+use schnapsen::player::PlayerId;
+use schnapsen::solver::solve;
+
+fn fast_config() -> PimcConfig {
+    PimcConfig {samples: 4, depth: 1}
+}
+
+#[test]
+fn test_pimc_single_legal_move() {
+    let game = Game::new_seeded(2);
+    let view = game.view_for(game.player_on_turn());
+    let mut bot = PimcBot::new_seeded(fast_config(), 1);
+
+    let mv = Move::DeclareWin;
+    assert_eq!(mv, bot.choose_move(&view, &[mv]));
+}
+
+#[test]
+fn test_pimc_plays_legal_moves_until_game_over() {
+    for seed in 0..3 {
+        let mut game = Game::new_seeded(seed);
+        let mut bot1 = PimcBot::new_seeded(fast_config(), seed);
+        let mut bot2 = PimcBot::new_seeded(fast_config(), seed + 100);
+
+        while !game.is_game_over() {
+            let player = game.player_on_turn();
+            let view = game.view_for(player);
+            let legal_moves = game.legal_moves();
+
+            let bot = match player {
+                PlayerId::Player1 => &mut bot1,
+                PlayerId::Player2 => &mut bot2
+            };
+            let mv = bot.choose_move(&view, &legal_moves);
+
+            assert!(legal_moves.contains(&mv));
+            assert!(game.apply(mv).is_ok());
+        }
+    }
+}
+
+#[test]
+fn test_pimc_is_deterministic_with_seed() {
+    let game = Game::new_seeded(7);
+    let view = game.view_for(game.player_on_turn());
+    let legal_moves = game.legal_moves();
+
+    let mut bot1 = PimcBot::new_seeded(fast_config(), 11);
+    let mut bot2 = PimcBot::new_seeded(fast_config(), 11);
+
+    for _ in 0..3 {
+        assert_eq!(bot1.choose_move(&view, &legal_moves),
+                   bot2.choose_move(&view, &legal_moves));
+    }
+}
+
+#[test]
+fn test_pimc_matches_solver_when_stock_is_empty() {
+    // With an empty stock the opponent's hand is known, so every sample is
+    // the actual game.
+    for seed in 0..6 {
+        let mut game = Game::new_seeded(seed);
+        let mut index = seed as usize;
+
+        while game.stock_size() > 0 && !game.is_game_over() {
+            let moves: Vec<Move> = game.legal_moves().into_iter()
+                .filter(|&mv| mv != Move::Close)
+                .collect();
+            index += 5;
+            assert!(game.apply(moves[index % moves.len()]).is_ok());
+        }
+
+        if game.is_game_over() {
+            continue;
+        }
+
+        let player = game.player_on_turn();
+        let view = game.view_for(player);
+        let mut bot = PimcBot::new_seeded(fast_config(), seed);
+        let mv = bot.choose_move(&view, &game.legal_moves());
+
+        let mut next = game.clone();
+        assert!(next.apply(mv).is_ok());
+        assert_eq!(solve(&game).unwrap().value * 100,
+                   evaluate(&next, player, 0));
+    }
+}
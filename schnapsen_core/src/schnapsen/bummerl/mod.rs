@@ -0,0 +1,112 @@
+use rand;
+use rand::Rng;
+use rand::isaac::Isaac64Rng;
+
+use schnapsen::ErrorKind;
+use schnapsen::deal_result::DealResult;
+use schnapsen::game::Game;
+use schnapsen::{generate_shuffled_deck, seeded_rng};
+use schnapsen::player::PlayerId;
+
+pub const GAME_POINTS_TO_WIN: u32 = 7;
+
+// A Bummerl: successive deals are played until one of the players reaches
+// GAME_POINTS_TO_WIN game points. The player on lead (forehand) alternates
+// between the deals.
+#[derive(Debug)]
+pub struct Match {
+    game: Game,
+    forehand: PlayerId,
+    finished_deals: Vec<DealResult>,
+    rng: Isaac64Rng
+}
+
+impl Default for Match {
+    fn default() -> Self {
+        Match::new()
+    }
+}
+
+impl Match {
+    pub fn new() -> Match {
+        Match::with_rng(rand::thread_rng().gen())
+    }
+
+    // The same seed always results in the same sequence of deals.
+    pub fn new_seeded(seed: u64) -> Match {
+        Match::with_rng(seeded_rng(seed))
+    }
+
+    fn with_rng(mut rng: Isaac64Rng) -> Match {
+        let forehand = PlayerId::Player1;
+        let game = new_deal(&mut rng, forehand);
+
+        Match {game, forehand, finished_deals: Vec::new(), rng}
+    }
+
+    pub fn game(&self) -> &Game {
+        &self.game
+    }
+
+    pub fn game_mut(&mut self) -> &mut Game {
+        &mut self.game
+    }
+
+    pub fn forehand(&self) -> PlayerId {
+        self.forehand
+    }
+
+    pub fn dealer(&self) -> PlayerId {
+        self.forehand.other()
+    }
+
+    // The results of all finished deals, including the current one if it is
+    // over.
+    pub fn deal_results(&self) -> Vec<DealResult> {
+        let mut results = self.finished_deals.clone();
+        results.extend(self.game.deal_result());
+        results
+    }
+
+    pub fn game_points(&self, player: PlayerId) -> u32 {
+        self.deal_results().iter()
+            .filter(|result| result.winner == player)
+            .map(|result| result.game_points)
+            .sum()
+    }
+
+    pub fn winner(&self) -> Option<PlayerId> {
+        [PlayerId::Player1, PlayerId::Player2].iter()
+            .find(|&&player| self.game_points(player) >= GAME_POINTS_TO_WIN)
+            .cloned()
+    }
+
+    pub fn is_over(&self) -> bool {
+        self.winner().is_some()
+    }
+
+    pub fn next_deal(&mut self) -> Result<(), ErrorKind> {
+        if self.is_over() {
+            return Err(ErrorKind::MatchOver);
+        }
+
+        let result = match self.game.deal_result() {
+            Some(result) => result,
+            None => return Err(ErrorKind::DealNotOver)
+        };
+
+        self.finished_deals.push(result);
+        self.forehand = self.forehand.other();
+        self.game = new_deal(&mut self.rng, self.forehand);
+
+        Ok(())
+    }
+}
+
+fn new_deal<R: Rng>(rng: &mut R, forehand: PlayerId) -> Game {
+    let deck = generate_shuffled_deck(rng);
+    Game::new_with_lead(deck, forehand).unwrap()
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,95 @@
+use super::*;
+
+fn play_out(game: &mut Game) {
+    while !game.is_game_over() {
+        if game.can_declare_win().is_ok() {
+            assert!(game.declare_win().is_ok());
+            continue;
+        }
+
+        let player = game.player_on_turn();
+        let card = game.get_player(player).hand.iter()
+            .find(|&card| game.can_play_card(card).is_ok())
+            .unwrap();
+        assert!(game.play_card(card).is_ok());
+    }
+}
+
+#[test]
+fn test_new_match() {
+    let bummerl = Match::new();
+
+    assert_eq!(PlayerId::Player1, bummerl.forehand());
+    assert_eq!(PlayerId::Player2, bummerl.dealer());
+    assert_eq!(PlayerId::Player1, bummerl.game().player_on_lead());
+    assert_eq!(0, bummerl.game_points(PlayerId::Player1));
+    assert_eq!(0, bummerl.game_points(PlayerId::Player2));
+    assert!(bummerl.deal_results().is_empty());
+    assert_eq!(None, bummerl.winner());
+}
+
+#[test]
+fn test_new_seeded() {
+    let mut bummerl1 = Match::new_seeded(7);
+    let mut bummerl2 = Match::new_seeded(7);
+
+    for _ in 0..2 {
+        assert_eq!(bummerl1.game().trump_card(), bummerl2.game().trump_card());
+        assert_eq!(bummerl1.game().get_player1().hand,
+                   bummerl2.game().get_player1().hand);
+
+        play_out(bummerl1.game_mut());
+        play_out(bummerl2.game_mut());
+        assert!(bummerl1.next_deal().is_ok());
+        assert!(bummerl2.next_deal().is_ok());
+    }
+}
+
+#[test]
+fn test_next_deal_not_over() {
+    let mut bummerl = Match::new();
+
+    assert_eq!(Err(ErrorKind::DealNotOver), bummerl.next_deal());
+}
+
+#[test]
+fn test_next_deal_alternates_forehand() {
+    let mut bummerl = Match::new();
+
+    play_out(bummerl.game_mut());
+    let result = bummerl.game().deal_result().unwrap();
+    assert_eq!(result.game_points, bummerl.game_points(result.winner));
+
+    assert!(bummerl.next_deal().is_ok());
+    assert_eq!(PlayerId::Player2, bummerl.forehand());
+    assert_eq!(PlayerId::Player2, bummerl.game().player_on_lead());
+    assert!(!bummerl.game().is_game_over());
+    assert_eq!(vec![result], bummerl.deal_results());
+}
+
+#[test]
+fn test_play_whole_match() {
+    let mut bummerl = Match::new();
+
+    loop {
+        play_out(bummerl.game_mut());
+
+        if bummerl.is_over() {
+            break;
+        }
+
+        assert!(bummerl.next_deal().is_ok());
+    }
+
+    let winner = bummerl.winner().unwrap();
+    assert!(bummerl.game_points(winner) >= GAME_POINTS_TO_WIN);
+    assert!(bummerl.game_points(winner.other()) < GAME_POINTS_TO_WIN);
+
+    let total: u32 = bummerl.deal_results().iter()
+        .map(|result| result.game_points)
+        .sum();
+    assert_eq!(total, bummerl.game_points(PlayerId::Player1)
+               + bummerl.game_points(PlayerId::Player2));
+
+    assert_eq!(Err(ErrorKind::MatchOver), bummerl.next_deal());
+}
@@ -0,0 +1,224 @@
+use std::fmt;
+use std::iter::FromIterator;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::de::Error;
+
+use cards::{Card, Rank, Suit};
+use schnapsen::{RANKS, SUITS};
+
+// A set of Schnapsen cards stored as a bitmask over the 20 cards of the deck.
+// Cards are iterated in deck order: by suit, then by rank.
+#[derive(Clone, Copy, Default, Eq, Hash, PartialEq)]
+pub struct CardSet {
+    bits: u32
+}
+
+const RANK_COUNT: u32 = 5;
+const ALL_BITS: u32 = (1 << 20) - 1;
+const SUIT_BITS: u32 = (1 << RANK_COUNT) - 1;
+
+// The cards of one rank in every suit.
+const RANK_BITS: u32 = 1 | 1 << RANK_COUNT | 1 << (2 * RANK_COUNT)
+    | 1 << (3 * RANK_COUNT);
+
+impl CardSet {
+    pub fn new() -> CardSet {
+        CardSet {bits: 0}
+    }
+
+    pub fn full() -> CardSet {
+        CardSet {bits: ALL_BITS}
+    }
+
+    // All cards of the given suit.
+    pub fn suit(suit: Suit) -> CardSet {
+        CardSet {bits: SUIT_BITS << (suit_index(suit) * RANK_COUNT)}
+    }
+
+    // All cards of the given rank. Empty for ranks not used in Schnapsen.
+    pub fn rank(rank: Rank) -> CardSet {
+        match rank_index(rank) {
+            Some(index) => CardSet {bits: RANK_BITS << index},
+            None => CardSet::new()
+        }
+    }
+
+    pub fn len(self) -> usize {
+        self.bits.count_ones() as usize
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.bits == 0
+    }
+
+    pub fn contains(self, card: Card) -> bool {
+        card_bit(card).map(|bit| self.bits & bit != 0).unwrap_or(false)
+    }
+
+    // Returns whether the card was not yet in the set. Panics if the card is
+    // not part of the Schnapsen deck.
+    pub fn insert(&mut self, card: Card) -> bool {
+        let bit = card_bit(card).expect("not a Schnapsen card");
+        let inserted = self.bits & bit == 0;
+        self.bits |= bit;
+        inserted
+    }
+
+    // Returns whether the card was in the set.
+    pub fn remove(&mut self, card: Card) -> bool {
+        let contained = self.contains(card);
+        if contained {
+            self.bits &= !card_bit(card).unwrap();
+        }
+
+        contained
+    }
+
+    pub fn union(self, other: CardSet) -> CardSet {
+        CardSet {bits: self.bits | other.bits}
+    }
+
+    pub fn intersection(self, other: CardSet) -> CardSet {
+        CardSet {bits: self.bits & other.bits}
+    }
+
+    pub fn difference(self, other: CardSet) -> CardSet {
+        CardSet {bits: self.bits & !other.bits}
+    }
+
+    // The cards of the given suit in this set.
+    pub fn of_suit(self, suit: Suit) -> CardSet {
+        self.intersection(CardSet::suit(suit))
+    }
+
+    pub fn has_suit(self, suit: Suit) -> bool {
+        !self.of_suit(suit).is_empty()
+    }
+
+    // The sum of the card values.
+    pub fn points(self) -> u32 {
+        RANKS.iter().enumerate()
+            .map(|(index, &rank)| {
+                let count = (self.bits & (RANK_BITS << index)).count_ones();
+                count * super::value(Card::new(Suit::Hearts, rank))
+            })
+            .sum()
+    }
+
+    pub fn iter(self) -> Iter {
+        Iter {bits: self.bits}
+    }
+
+    pub fn to_vec(self) -> Vec<Card> {
+        self.iter().collect()
+    }
+}
+
+pub struct Iter {
+    bits: u32
+}
+
+impl Iterator for Iter {
+    type Item = Card;
+
+    fn next(&mut self) -> Option<Card> {
+        if self.bits == 0 {
+            return None;
+        }
+
+        let index = self.bits.trailing_zeros();
+        self.bits &= self.bits - 1;
+        Some(card_at(index))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.bits.count_ones() as usize;
+        (len, Some(len))
+    }
+}
+
+impl IntoIterator for CardSet {
+    type Item = Card;
+    type IntoIter = Iter;
+
+    fn into_iter(self) -> Iter {
+        self.iter()
+    }
+}
+
+impl FromIterator<Card> for CardSet {
+    fn from_iter<I: IntoIterator<Item = Card>>(iter: I) -> CardSet {
+        let mut set = CardSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<Card> for CardSet {
+    fn extend<I: IntoIterator<Item = Card>>(&mut self, iter: I) {
+        for card in iter {
+            self.insert(card);
+        }
+    }
+}
+
+impl<'a> From<&'a [Card]> for CardSet {
+    fn from(cards: &'a [Card]) -> CardSet {
+        cards.iter().cloned().collect()
+    }
+}
+
+impl From<Vec<Card>> for CardSet {
+    fn from(cards: Vec<Card>) -> CardSet {
+        cards.into_iter().collect()
+    }
+}
+
+impl fmt::Debug for CardSet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+// Serialized as a list of cards, like the vectors it replaces.
+impl Serialize for CardSet {
+    fn serialize<S: Serializer>(&self, serializer: S)
+                                -> Result<S::Ok, S::Error> {
+        self.to_vec().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for CardSet {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D)
+                                         -> Result<CardSet, D::Error> {
+        let cards = Vec::<Card>::deserialize(deserializer)?;
+
+        match cards.iter().find(|&&card| card_bit(card).is_none()) {
+            Some(card) => Err(D::Error::custom(
+                format!("not a Schnapsen card: {:?}", card))),
+            None => Ok(CardSet::from(cards))
+        }
+    }
+}
+
+fn suit_index(suit: Suit) -> u32 {
+    SUITS.iter().position(|&other| other == suit).unwrap() as u32
+}
+
+fn rank_index(rank: Rank) -> Option<u32> {
+    RANKS.iter().position(|&other| other == rank).map(|index| index as u32)
+}
+
+fn card_bit(card: Card) -> Option<u32> {
+    rank_index(card.rank())
+        .map(|rank| 1 << (suit_index(card.suit()) * RANK_COUNT + rank))
+}
+
+fn card_at(index: u32) -> Card {
+    Card::new(SUITS[(index / RANK_COUNT) as usize],
+              RANKS[(index % RANK_COUNT) as usize])
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,85 @@
+use super::*;
+use schnapsen::generate_deck;
+
+#[test]
+fn test_card_set_insert_remove() {
+    let mut set = CardSet::new();
+    let card = Card::new(Suit::Bells, Rank::Ober);
+
+    assert!(set.is_empty());
+    assert!(set.insert(card));
+    assert!(!set.insert(card));
+    assert!(set.contains(card));
+    assert_eq!(1, set.len());
+
+    assert!(set.remove(card));
+    assert!(!set.remove(card));
+    assert!(set.is_empty());
+}
+
+#[test]
+fn test_card_set_iterates_in_deck_order() {
+    let deck = generate_deck();
+
+    assert_eq!(deck, CardSet::full().to_vec());
+
+    let mut reversed = deck.clone();
+    reversed.reverse();
+    assert_eq!(deck, CardSet::from(reversed).to_vec());
+}
+
+#[test]
+fn test_card_set_non_schnapsen_card() {
+    let seven = Card::new(Suit::Hearts, Rank::Seven);
+    let mut set = CardSet::full();
+
+    assert!(!set.contains(seven));
+    assert!(!set.remove(seven));
+    assert!(CardSet::rank(Rank::Seven).is_empty());
+}
+
+#[test]
+fn test_card_set_suits_and_ranks() {
+    let hand = CardSet::from(vec![Card::new(Suit::Hearts, Rank::Ace),
+                                  Card::new(Suit::Hearts, Rank::Unter),
+                                  Card::new(Suit::Leaves, Rank::King)]);
+
+    assert_eq!(2, hand.of_suit(Suit::Hearts).len());
+    assert!(hand.has_suit(Suit::Leaves));
+    assert!(!hand.has_suit(Suit::Bells));
+
+    for &suit in SUITS.iter() {
+        assert_eq!(5, CardSet::suit(suit).len());
+    }
+
+    for &rank in RANKS.iter() {
+        assert_eq!(4, CardSet::rank(rank).len());
+    }
+
+    let others = CardSet::full().difference(hand);
+    assert_eq!(17, others.len());
+    assert_eq!(CardSet::full(), others.union(hand));
+    assert!(others.intersection(hand).is_empty());
+}
+
+#[test]
+fn test_card_set_points() {
+    assert_eq!(120, CardSet::full().points());
+    assert_eq!(30, CardSet::suit(Suit::Acorns).points());
+    assert_eq!(44, CardSet::rank(Rank::Ace).points());
+    assert_eq!(0, CardSet::new().points());
+}
+
+#[test]
+fn test_card_set_serialization() {
+    let hand = CardSet::from(vec![Card::new(Suit::Leaves, Rank::Ten),
+                                  Card::new(Suit::Bells, Rank::Ober)]);
+
+    let json = ::serde_json::to_string(&hand).unwrap();
+    assert_eq!(::serde_json::to_string(&hand.to_vec()).unwrap(), json);
+    assert_eq!(hand, ::serde_json::from_str(&json).unwrap());
+
+    let seven = vec![Card::new(Suit::Hearts, Rank::Seven)];
+    let json = ::serde_json::to_string(&seven).unwrap();
+    assert!(::serde_json::from_str::<CardSet>(&json).is_err());
+}
@@ -0,0 +1,62 @@
+use std::cmp;
+
+use schnapsen::game_data::Closing;
+use schnapsen::player::{Player, PlayerId};
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum DealEndReason {
+    DeclaredWin,
+    LastTrick,
+    CloserFailed
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct DealResult {
+    pub winner: PlayerId,
+    pub game_points: u32,
+    pub reason: DealEndReason
+}
+
+impl DealResult {
+    pub fn new(winner: PlayerId, loser: &Player, reason: DealEndReason)
+               -> DealResult {
+        let game_points = game_points(loser.score(), !loser.wins.is_empty());
+        DealResult {winner, game_points, reason}
+    }
+
+    // The result of a deal in which the deck was closed. The game points are
+    // based on the opponent's score at the time of closing. If the closer
+    // did not win the deal, the opponent gets at least 2 game points.
+    pub fn closed(winner: PlayerId, closing: &Closing, reason: DealEndReason)
+                  -> DealResult {
+        let game_points = game_points(closing.opponent_score,
+                                      closing.opponent_has_trick);
+
+        if winner == closing.closer {
+            DealResult {winner, game_points, reason}
+        } else {
+            DealResult {winner, game_points: cmp::max(2, game_points),
+                        reason: DealEndReason::CloserFailed}
+        }
+    }
+
+    pub fn loser(&self) -> PlayerId {
+        self.winner.other()
+    }
+}
+
+// The number of game points the winner receives, based on the loser's score:
+// 3 if the loser has not taken a trick (Schwarz), 2 if the loser has less than
+// 33 points (Schneider) and 1 otherwise.
+pub fn game_points(loser_score: u32, loser_has_trick: bool) -> u32 {
+    if !loser_has_trick {
+        3
+    } else if loser_score < 33 {
+        2
+    } else {
+        1
+    }
+}
+
+#[cfg(test)]
+mod tests;
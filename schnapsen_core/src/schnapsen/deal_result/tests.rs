@@ -0,0 +1,82 @@
+use super::*;
+use cards::{Card, Suit, Rank};
+
+#[test]
+fn test_game_points_no_trick() {
+    assert_eq!(3, game_points(0, false));
+}
+
+#[test]
+fn test_game_points_under_33() {
+    assert_eq!(2, game_points(0, true));
+    assert_eq!(2, game_points(32, true));
+}
+
+#[test]
+fn test_game_points_at_least_33() {
+    assert_eq!(1, game_points(33, true));
+    assert_eq!(1, game_points(65, true));
+}
+
+#[test]
+fn test_deal_result_new() {
+    let wins = vec![Card::new(Suit::Leaves, Rank::Ace),
+                    Card::new(Suit::Leaves, Rank::Unter)];
+    let loser = Player {wins: wins.into(), ..Default::default()};
+
+    let result = DealResult::new(PlayerId::Player1, &loser,
+                                 DealEndReason::DeclaredWin);
+
+    assert_eq!(PlayerId::Player1, result.winner);
+    assert_eq!(PlayerId::Player2, result.loser());
+    assert_eq!(2, result.game_points);
+    assert_eq!(DealEndReason::DeclaredWin, result.reason);
+}
+
+#[test]
+fn test_deal_result_new_loser_without_trick() {
+    let loser = Player {twenties: vec![Suit::Bells], ..Default::default()};
+
+    let result = DealResult::new(PlayerId::Player2, &loser,
+                                 DealEndReason::LastTrick);
+
+    assert_eq!(3, result.game_points);
+}
+
+#[test]
+fn test_deal_result_closed_closer_wins() {
+    let closing = Closing {closer: PlayerId::Player1, stock_size: 6,
+                           opponent_score: 40, opponent_has_trick: true};
+
+    let result = DealResult::closed(PlayerId::Player1, &closing,
+                                    DealEndReason::DeclaredWin);
+
+    assert_eq!(PlayerId::Player1, result.winner);
+    assert_eq!(1, result.game_points);
+    assert_eq!(DealEndReason::DeclaredWin, result.reason);
+}
+
+#[test]
+fn test_deal_result_closed_closer_fails() {
+    let closing = Closing {closer: PlayerId::Player1, stock_size: 6,
+                           opponent_score: 40, opponent_has_trick: true};
+
+    let result = DealResult::closed(PlayerId::Player2, &closing,
+                                    DealEndReason::LastTrick);
+
+    assert_eq!(PlayerId::Player2, result.winner);
+    assert_eq!(2, result.game_points);
+    assert_eq!(DealEndReason::CloserFailed, result.reason);
+}
+
+#[test]
+fn test_deal_result_closed_closer_fails_opponent_without_trick() {
+    let closing = Closing {closer: PlayerId::Player2, stock_size: 8,
+                           opponent_score: 0, opponent_has_trick: false};
+
+    let result = DealResult::closed(PlayerId::Player1, &closing,
+                                    DealEndReason::DeclaredWin);
+
+    assert_eq!(3, result.game_points);
+    assert_eq!(DealEndReason::CloserFailed, result.reason);
+}
@@ -0,0 +1,17 @@
+use cards::{Card, Suit};
+use schnapsen::deal_result::DealResult;
+use schnapsen::player::PlayerId;
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum GameEvent {
+    CardPlayed {player: PlayerId, card: Card},
+    TrickWon {winner: PlayerId, lead_card: Card, reply_card: Card,
+              points: u32},
+    CardsDealt {player1_card: Card, player2_card: Card},
+    // The trump card that the player took in exchange for the trump Unter.
+    TrumpExchanged {player: PlayerId, trump_card: Card},
+    MarriageDeclared {player: PlayerId, suit: Suit, points: u32},
+    Closed {player: PlayerId},
+    WinDeclared {player: PlayerId},
+    DealOver(DealResult)
+}
@@ -1,22 +1,121 @@
+use std::hash::{Hash, Hasher};
+
 use rand;
 use rand::Rng;
+use serde::{Deserialize, Deserializer};
 
 use cards::{Card, Suit, Rank};
-use schnapsen::ErrorKind;
+use schnapsen::{DeckError, ErrorKind};
+use schnapsen::card_set::CardSet;
+use schnapsen::deal_result::{DealEndReason, DealResult};
+use schnapsen::event::GameEvent;
+use schnapsen::moves::Move;
 use schnapsen::player::{Player, PlayerId};
+use schnapsen::player_view::PlayerView;
+use schnapsen::trick::Trick;
+use schnapsen::zobrist;
 
-use schnapsen::generate_deck;
+use schnapsen::{generate_deck, generate_seeded_deck, generate_shuffled_deck,
+                validate_deck, value, SUITS};
 
-use super::game_data::PublicGameData;
+use super::game_data::{Closing, DealOrigin, PublicGameData};
 use super::player_game::PlayerGame;
+use super::undo::{PlayerUndo, UndoEntry};
 
-#[derive(Debug)]
+#[derive(Clone, Debug, Serialize)]
 pub struct Game {
     stock: Vec<Card>,
     player1: Player,
     player2: Player,
     
-    public_data: PublicGameData
+    public_data: PublicGameData,
+    result: Option<DealResult>,
+    events: Vec<GameEvent>,
+
+    // Where the deal started from and the moves made since, if the game was
+    // dealt from a deck.
+    #[serde(default)]
+    origin: Option<DealOrigin>,
+    #[serde(default)]
+    moves: Vec<Move>,
+    #[serde(default)]
+    tricks: Vec<Trick>,
+
+    // What is needed to take back the moves, and the moves taken back that
+    // can still be redone, the last one first.
+    #[serde(default)]
+    history: Vec<UndoEntry>,
+    #[serde(default)]
+    redo_moves: Vec<Move>,
+    // The number of events drained so far.
+    #[serde(default)]
+    drained_events: usize,
+
+    // The Zobrist hash of the position, updated with every move. It is not
+    // serialized but recomputed when the game is deserialized.
+    #[serde(skip)]
+    hash: u64
+}
+
+// The serialized fields of Game.
+#[derive(Deserialize)]
+#[serde(rename = "Game")]
+struct GameFields {
+    stock: Vec<Card>,
+    player1: Player,
+    player2: Player,
+    public_data: PublicGameData,
+    result: Option<DealResult>,
+    events: Vec<GameEvent>,
+    #[serde(default)]
+    origin: Option<DealOrigin>,
+    #[serde(default)]
+    moves: Vec<Move>,
+    #[serde(default)]
+    tricks: Vec<Trick>,
+    #[serde(default)]
+    history: Vec<UndoEntry>,
+    #[serde(default)]
+    redo_moves: Vec<Move>,
+    #[serde(default)]
+    drained_events: usize
+}
+
+impl<'de> Deserialize<'de> for Game {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D)
+                                         -> Result<Game, D::Error> {
+        let fields = GameFields::deserialize(deserializer)?;
+        let mut game = Game {
+            stock: fields.stock, player1: fields.player1,
+            player2: fields.player2, public_data: fields.public_data,
+            result: fields.result, events: fields.events,
+            origin: fields.origin, moves: fields.moves, tricks: fields.tricks,
+            history: fields.history, redo_moves: fields.redo_moves,
+            drained_events: fields.drained_events, hash: 0
+        };
+
+        game.rehash();
+        Ok(game)
+    }
+}
+
+// Two games are equal if they are in the same position, regardless of the
+// player names, the events and what the players have revealed.
+impl PartialEq for Game {
+    fn eq(&self, other: &Game) -> bool {
+        self.stock == other.stock
+            && self.player1.same_position(&other.player1)
+            && self.player2.same_position(&other.player2)
+            && self.public_data == other.public_data
+    }
+}
+
+impl Eq for Game {}
+
+impl Hash for Game {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u64(self.hash);
+    }
 }
 
 impl Default for Game {
@@ -27,12 +126,20 @@ impl Default for Game {
 }
 
 impl Game {
-    fn new_(mut deck: Vec<Card>) -> Option<Game> {
+    fn new_(deck: Vec<Card>) -> Option<Game> {
+        Game::new_with_lead(deck, PlayerId::Player1)
+    }
+
+    pub(super) fn new_with_lead(mut deck: Vec<Card>, player_on_lead: PlayerId)
+                                -> Option<Game> {
         // Don't allow an odd number of cards in the deck.
         if deck.len() % 2 == 1 {
             return None;
         }
         
+        let origin = DealOrigin {deck: deck.clone(), player_on_lead,
+                                 seed: None};
+
         let deck_length = deck.len();
         let hand1 = deck.split_off(deck_length - 5);
         
@@ -42,25 +149,135 @@ impl Game {
         let stock = deck;
         let trump = stock[0].suit();
 
-        let public_data = PublicGameData {trump, closed: false, winner: None,
-                                          player_on_lead: PlayerId::Player1,
+        let public_data = PublicGameData {trump, closed: false, closing: None,
+                                          winner: None, player_on_lead,
                                           first_card_in_trick: None};
-        let game = Game {stock,
-              player1: Player {name: "Player1".to_string(),
-                               hand: hand1, ..Default::default()},
-              player2: Player {name: "Player2".to_string(),
-                               hand: hand2, ..Default::default()},
-              public_data
-        };
+        let player1 = Player {name: "Player1".to_string(),
+                              hand: CardSet::from(hand1), ..Default::default()};
+        let player2 = Player {name: "Player2".to_string(),
+                              hand: CardSet::from(hand2), ..Default::default()};
 
+        let mut game = Game::from_parts(stock, player1, player2, public_data);
+        game.origin = Some(origin);
         Some(game)
     }
+
+    pub(super) fn from_parts(stock: Vec<Card>, player1: Player,
+                             player2: Player, public_data: PublicGameData)
+                             -> Game {
+        let mut game = Game {stock, player1, player2, public_data,
+                             result: None, events: Vec::new(), origin: None,
+                             moves: Vec::new(), tricks: Vec::new(),
+                             history: Vec::new(),
+                             redo_moves: Vec::new(), drained_events: 0,
+                             hash: 0};
+        game.rehash();
+        game
+    }
+
+    // Recomputes the hash from scratch, e.g. after deserialization.
+    pub(super) fn rehash(&mut self) {
+        self.hash = self.compute_hash();
+    }
+
+    fn compute_hash(&self) -> u64 {
+        zobrist::position(&self.stock, &self.player1, &self.player2,
+                          &self.public_data)
+    }
+
+    // The deck and lead the deal started from. None if the game was set up in
+    // an arbitrary position.
+    pub fn origin(&self) -> Option<&DealOrigin> {
+        self.origin.as_ref()
+    }
+
+    // All moves made in this game, in order.
+    pub fn moves(&self) -> &[Move] {
+        &self.moves
+    }
+
+    // All completed tricks, in order.
+    pub fn tricks(&self) -> &[Trick] {
+        &self.tricks
+    }
+
+    pub fn last_trick(&self) -> Option<&Trick> {
+        self.tricks.last()
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.history.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.redo_moves.is_empty()
+    }
+
+    // Takes back the last move and returns it. Events of the move that have
+    // not been drained yet are removed.
+    pub fn undo(&mut self) -> Option<Move> {
+        let entry = self.history.pop()?;
+
+        entry.restore_stock(&mut self.stock);
+        entry.player1.restore(&mut self.player1);
+        entry.player2.restore(&mut self.player2);
+        self.public_data = entry.public_data;
+        self.result = entry.result;
+        self.hash = entry.hash;
+        self.tricks.truncate(entry.tricks);
+
+        let undrained = entry.event_count.saturating_sub(self.drained_events);
+        self.events.truncate(undrained);
+
+        self.moves.pop();
+        self.redo_moves.push(entry.mv);
+        Some(entry.mv)
+    }
+
+    // Makes the last move taken back again and returns it. Making any other
+    // move discards the moves that could be redone.
+    pub fn redo(&mut self) -> Option<Move> {
+        let mv = self.redo_moves.pop()?;
+        let redo_moves = ::std::mem::take(&mut self.redo_moves);
+
+        // The move was legal when it was taken back.
+        self.apply(mv).expect("A move to redo must be legal.");
+        self.redo_moves = redo_moves;
+        Some(mv)
+    }
+
+    pub fn set_player_name(&mut self, player: PlayerId, name: &str) {
+        self.get_player_mut(player).name = name.to_string();
+    }
+
+    // A stable Zobrist hash of the position that is updated incrementally.
+    pub fn zobrist_hash(&self) -> u64 {
+        self.hash
+    }
     
+    // The cards are dealt from the end of the deck: the last five cards form
+    // the hand of Player1, the five before them the hand of Player2. The first
+    // card of the deck is the trump card.
+    pub fn from_deck(deck: Vec<Card>) -> Result<Game, DeckError> {
+        validate_deck(&deck)?;
+        Ok(Game::new_(deck).unwrap())
+    }
+
     pub fn new_random() -> Game {
-        let mut deck = generate_deck();
-        let mut rng = rand::isaac::IsaacRng::new_unseeded();
-        rng.shuffle(&mut deck);
+        Game::new_with_rng(&mut rand::thread_rng())
+    }
 
+    // The same seed always results in the same deal.
+    pub fn new_seeded(seed: u64) -> Game {
+        let mut game = Game::new_(generate_seeded_deck(seed)).unwrap();
+        if let Some(ref mut origin) = game.origin {
+            origin.seed = Some(seed);
+        }
+        game
+    }
+
+    pub fn new_with_rng<R: Rng>(rng: &mut R) -> Game {
+        let deck = generate_shuffled_deck(rng);
         Game::new_(deck).unwrap()
     }
 
@@ -79,6 +296,50 @@ impl Game {
         }
     }
 
+    // What the given player is allowed to know about the game.
+    pub fn view_for(&self, player_id: PlayerId) -> PlayerView {
+        let player = self.get_player(player_id);
+        let opponent = self.get_player(player_id.other());
+
+        let opponent_revealed = opponent.revealed.iter()
+            .filter(|&&card| opponent.hand.contains(card))
+            .cloned()
+            .collect();
+
+        PlayerView {
+            player_id,
+            hand: player.hand.to_vec(),
+
+            trump: self.trump(),
+            trump_card: self.trump_card(),
+            closed_trump_card: if self.is_closed() {
+                self.stock.first().cloned()
+            } else {
+                None
+            },
+            stock_size: self.stock.len() as u32,
+            closed: self.is_closed(),
+            closing: self.closing(),
+
+            player_on_lead: self.player_on_lead(),
+            card_on_lead: self.public_data.first_card_in_trick,
+
+            own_wins: player.wins.to_vec(),
+            own_twenties: player.twenties.clone(),
+            own_forty: player.forty.is_some(),
+
+            opponent_hand_size: opponent.hand.len() as u32,
+            opponent_revealed,
+            opponent_wins: opponent.wins.to_vec(),
+            opponent_twenties: opponent.twenties.clone(),
+            opponent_forty: opponent.forty.is_some(),
+
+            last_trick: self.last_trick().cloned(),
+
+            deal_result: self.deal_result()
+        }
+    }
+
     pub fn player_on_lead(&self) -> PlayerId {
         self.public_data.player_on_lead
     }
@@ -103,6 +364,11 @@ impl Game {
         self.public_data.closed
     }
 
+    // The number of cards in the stock, including the trump card.
+    pub fn stock_size(&self) -> u32 {
+        self.stock.len() as u32
+    }
+
     pub fn can_close(&self) -> Result<(), ErrorKind> {
         let player_on_turn = self.player_on_turn();
         let data_as_player = self.get_data_as_player(player_on_turn);
@@ -111,9 +377,31 @@ impl Game {
     
     pub fn close(&mut self) -> Result<(), ErrorKind> {
         let player_on_turn = self.player_on_turn();
-        let mut data_as_player_mut
-            = self.get_data_as_player_mut(player_on_turn);
-        data_as_player_mut.close() 
+        let entry = self.undo_entry(Move::Close);
+
+        {
+            let mut data_as_player_mut
+                = self.get_data_as_player_mut(player_on_turn);
+            data_as_player_mut.close()?;
+        }
+
+        let closing = {
+            let opponent = self.get_player(player_on_turn.other());
+            Closing {closer: player_on_turn,
+                     stock_size: self.stock.len() as u32,
+                     opponent_score: opponent.score(),
+                     opponent_has_trick: !opponent.wins.is_empty()}
+        };
+        self.public_data.closing = Some(closing);
+        self.hash ^= zobrist::closed() ^ zobrist::closing(&closing);
+        self.events.push(GameEvent::Closed {player: player_on_turn});
+        self.push_history(entry);
+
+        Ok(())
+    }
+
+    pub fn closing(&self) -> Option<Closing> {
+        self.public_data.closing
     }
 
     pub fn is_game_over(&self) -> bool {
@@ -124,6 +412,20 @@ impl Game {
         self.public_data.winner
     }
 
+    pub fn deal_result(&self) -> Option<DealResult> {
+        self.result
+    }
+
+    // The events that have not been drained yet.
+    pub fn events(&self) -> &[GameEvent] {
+        &self.events
+    }
+
+    pub fn drain_events(&mut self) -> Vec<GameEvent> {
+        self.drained_events += self.events.len();
+        self.events.drain(..).collect()
+    }
+
     pub fn can_exchange_trump(&self) -> Result<(), ErrorKind> {
         let player_on_turn = self.player_on_turn();
         let data_as_player = self.get_data_as_player(player_on_turn);
@@ -131,8 +433,9 @@ impl Game {
     }
 
     pub fn exchange_trump(&mut self) -> Result<(), ErrorKind> {
+        let player_on_turn = self.player_on_turn();
+        let entry = self.undo_entry(Move::ExchangeTrump);
         let res = {
-            let player_on_turn = self.player_on_turn();
             let mut data_as_player_mut
                 = self.get_data_as_player_mut(player_on_turn);
 
@@ -141,7 +444,19 @@ impl Game {
 
         if res.is_ok() {
             let trump = self.public_data.trump;
-            self.stock[0] = Card::new(trump, Rank::Unter);
+            let trump_card = self.stock[0];
+            let trump_unter = Card::new(trump, Rank::Unter);
+            self.stock[0] = trump_unter;
+            self.get_player_mut(player_on_turn).revealed.push(trump_card);
+
+            self.hash ^= zobrist::hand(player_on_turn, trump_unter)
+                ^ zobrist::hand(player_on_turn, trump_card)
+                ^ zobrist::stock(0, trump_card)
+                ^ zobrist::stock(0, trump_unter);
+
+            self.events.push(GameEvent::TrumpExchanged {player: player_on_turn,
+                                                        trump_card});
+            self.push_history(entry);
         }
 
         res
@@ -156,9 +471,18 @@ impl Game {
     
     pub fn declare_win(&mut self) -> Result<(), ErrorKind> {
         let player_on_turn = self.player_on_turn();
-        let mut data_as_player_mut
-            = self.get_data_as_player_mut(player_on_turn);
-        data_as_player_mut.declare_win()
+        let entry = self.undo_entry(Move::DeclareWin);
+
+        {
+            let mut data_as_player_mut
+                = self.get_data_as_player_mut(player_on_turn);
+            data_as_player_mut.declare_win()?;
+        }
+
+        self.push_history(entry);
+        self.events.push(GameEvent::WinDeclared {player: player_on_turn});
+        self.finish_deal(DealEndReason::DeclaredWin);
+        Ok(())
     }
 
     pub fn can_play_card_twenty(&self, card: Card) -> Result<(), ErrorKind> {
@@ -169,9 +493,21 @@ impl Game {
 
     pub fn play_card_twenty(&mut self, card: Card) -> Result<(), ErrorKind> {
         let player_on_turn = self.player_on_turn();
-        let mut data_as_player_mut
-            = self.get_data_as_player_mut(player_on_turn);
-        data_as_player_mut.play_card_twenty(card)
+        let entry = self.undo_entry(Move::PlayTwenty(card));
+
+        {
+            let mut data_as_player_mut
+                = self.get_data_as_player_mut(player_on_turn);
+            data_as_player_mut.play_card_twenty(card)?;
+        }
+
+        self.push_history(entry);
+        self.hash ^= zobrist::twenty(player_on_turn, card.suit());
+        self.hash_lead_card(player_on_turn, card);
+        self.reveal_marriage_partner(player_on_turn, card);
+        self.declare_marriage(player_on_turn, card.suit());
+        self.events.push(GameEvent::CardPlayed {player: player_on_turn, card});
+        Ok(())
     }
 
     pub fn can_declare_twenty_win(&self, suit: Suit) -> Result<(), ErrorKind> {
@@ -182,9 +518,19 @@ impl Game {
 
     pub fn declare_twenty_win(&mut self, suit: Suit) -> Result<(), ErrorKind> {
         let player_on_turn = self.player_on_turn();
-        let mut data_as_player_mut
-            = self.get_data_as_player_mut(player_on_turn);
-        data_as_player_mut.declare_twenty_win(suit)
+        let entry = self.undo_entry(Move::DeclareTwentyWin(suit));
+
+        {
+            let mut data_as_player_mut
+                = self.get_data_as_player_mut(player_on_turn);
+            data_as_player_mut.declare_twenty_win(suit)?;
+        }
+
+        self.push_history(entry);
+        self.declare_marriage(player_on_turn, suit);
+        self.events.push(GameEvent::WinDeclared {player: player_on_turn});
+        self.finish_deal(DealEndReason::DeclaredWin);
+        Ok(())
     }
 
     pub fn can_play_card_forty(&self, card: Card) -> Result<(), ErrorKind> {
@@ -195,9 +541,21 @@ impl Game {
 
     pub fn play_card_forty(&mut self, card: Card) -> Result<(), ErrorKind> {
         let player_on_turn = self.player_on_turn();
-        let mut data_as_player_mut
-            = self.get_data_as_player_mut(player_on_turn);
-        data_as_player_mut.play_card_forty(card)
+        let entry = self.undo_entry(Move::PlayForty(card));
+
+        {
+            let mut data_as_player_mut
+                = self.get_data_as_player_mut(player_on_turn);
+            data_as_player_mut.play_card_forty(card)?;
+        }
+
+        self.push_history(entry);
+        self.hash ^= zobrist::forty(player_on_turn);
+        self.hash_lead_card(player_on_turn, card);
+        self.reveal_marriage_partner(player_on_turn, card);
+        self.declare_marriage(player_on_turn, card.suit());
+        self.events.push(GameEvent::CardPlayed {player: player_on_turn, card});
+        Ok(())
     }
 
     pub fn can_declare_forty_win(&self) -> Result<(), ErrorKind> {
@@ -208,9 +566,20 @@ impl Game {
 
     pub fn declare_forty_win(&mut self) -> Result<(), ErrorKind> {
         let player_on_turn = self.player_on_turn();
-        let mut data_as_player_mut
-            = self.get_data_as_player_mut(player_on_turn);
-        data_as_player_mut.declare_forty_win()
+        let entry = self.undo_entry(Move::DeclareFortyWin);
+
+        {
+            let mut data_as_player_mut
+                = self.get_data_as_player_mut(player_on_turn);
+            data_as_player_mut.declare_forty_win()?;
+        }
+
+        self.push_history(entry);
+        let trump = self.trump();
+        self.declare_marriage(player_on_turn, trump);
+        self.events.push(GameEvent::WinDeclared {player: player_on_turn});
+        self.finish_deal(DealEndReason::DeclaredWin);
+        Ok(())
     }
 
     pub fn can_play_card(&self, card: Card) -> Result<(), ErrorKind> {
@@ -222,6 +591,8 @@ impl Game {
     pub fn play_card(&mut self, card: Card)
                      -> Result<Option<(Card, Card)>, ErrorKind> {
         let player_on_turn = self.player_on_turn();
+        let entry = self.undo_entry(Move::PlayCard(card));
+        let player_on_lead = self.player_on_lead();
 
         let result: Option<(PlayerId, Card)> = {
             let mut data_as_player_mut
@@ -229,23 +600,105 @@ impl Game {
             data_as_player_mut.play_card(card)?
         };
 
+        self.events.push(GameEvent::CardPlayed {player: player_on_turn, card});
+        self.push_history(entry);
+
         if let Some((winner_id, card_on_lead)) = result {
             if player_on_turn != winner_id {
                 self.add_cards_to_wins(winner_id, &[card_on_lead, card]);
             }
 
+            self.hash ^= zobrist::hand(player_on_turn, card)
+                ^ zobrist::trick(card_on_lead)
+                ^ zobrist::wins(winner_id, card_on_lead)
+                ^ zobrist::wins(winner_id, card)
+                ^ zobrist::lead(player_on_lead)
+                ^ zobrist::lead(winner_id);
+
+            // The cards of this trick are already in the winner's pile, so
+            // tricks played before this game was set up are counted too.
+            let number = (self.player1.wins.len()
+                          + self.player2.wins.len()) as u32 / 2;
+            self.tricks.push(Trick {number, leader: player_on_lead,
+                                    lead_card: card_on_lead,
+                                    reply_card: card, winner: winner_id,
+                                    stock_size: self.stock.len() as u32});
+
+            self.events.push(GameEvent::TrickWon {
+                winner: winner_id, lead_card: card_on_lead, reply_card: card,
+                points: value(card_on_lead) + value(card)
+            });
+
             let dealed_cards
                 = self.deal_if_not_closed_or_empty(winner_id);
+
+            if let Some((player1_card, player2_card)) = dealed_cards {
+                self.events.push(GameEvent::CardsDealt {player1_card,
+                                                        player2_card});
+            }
             
             self.public_data.player_on_lead = winner_id;
             self.public_data.first_card_in_trick = None;
 
+            if self.is_game_over() {
+                self.finish_deal(DealEndReason::LastTrick);
+            }
+
             Ok(dealed_cards)
         } else {
+            self.hash_lead_card(player_on_turn, card);
             Ok(None)
         }
     }
 
+    // All moves the player on turn can make.
+    pub fn legal_moves(&self) -> Vec<Move> {
+        if self.is_game_over() {
+            return Vec::new();
+        }
+
+        let hand = self.get_player(self.player_on_turn()).hand;
+
+        let mut candidates = Vec::new();
+        candidates.extend(hand.iter().map(Move::PlayCard));
+        candidates.extend(hand.iter().map(Move::PlayTwenty));
+        candidates.extend(hand.iter().map(Move::PlayForty));
+        candidates.extend(SUITS.iter()
+                          .map(|&suit| Move::DeclareTwentyWin(suit)));
+        candidates.extend_from_slice(&[Move::DeclareFortyWin, Move::DeclareWin,
+                                       Move::Close, Move::ExchangeTrump]);
+
+        candidates.into_iter()
+            .filter(|&mv| self.can_apply(mv).is_ok())
+            .collect()
+    }
+
+    pub fn can_apply(&self, mv: Move) -> Result<(), ErrorKind> {
+        match mv {
+            Move::Close => self.can_close(),
+            Move::ExchangeTrump => self.can_exchange_trump(),
+            Move::PlayTwenty(card) => self.can_play_card_twenty(card),
+            Move::DeclareTwentyWin(suit) => self.can_declare_twenty_win(suit),
+            Move::PlayForty(card) => self.can_play_card_forty(card),
+            Move::DeclareFortyWin => self.can_declare_forty_win(),
+            Move::DeclareWin => self.can_declare_win(),
+            Move::PlayCard(card) => self.can_play_card(card)
+        }
+    }
+
+    pub fn apply(&mut self, mv: Move) -> Result<(), ErrorKind> {
+        match mv {
+            Move::Close => self.close(),
+            Move::ExchangeTrump => self.exchange_trump(),
+            Move::PlayTwenty(card) => self.play_card_twenty(card),
+            Move::DeclareTwentyWin(suit) => self.declare_twenty_win(suit),
+            Move::PlayForty(card) => self.play_card_forty(card),
+            Move::DeclareFortyWin => self.declare_forty_win(),
+            Move::DeclareWin => self.declare_win(),
+            Move::PlayCard(card) => self.play_card(card).map(|_| ())
+        }
+    }
+
     fn get_player_mut(&mut self, player: PlayerId) -> &mut Player {
         match player {
             PlayerId::Player1 => &mut self.player1,
@@ -280,7 +733,73 @@ impl Game {
 
     fn add_cards_to_wins(&mut self, player: PlayerId, cards: &[Card]) {
         let player_wins = &mut self.get_player_mut(player).wins;
-        player_wins.extend_from_slice(cards);
+        player_wins.extend(cards.iter().cloned());
+    }
+
+    fn undo_entry(&self, mv: Move) -> UndoEntry {
+        let size = self.stock.len();
+        let stock_top = if size >= 2 {
+            [Some(self.stock[size - 2]), Some(self.stock[size - 1])]
+        } else {
+            [None, None]
+        };
+
+        UndoEntry {mv,
+                   player1: PlayerUndo::save(&self.player1),
+                   player2: PlayerUndo::save(&self.player2),
+                   public_data: self.public_data,
+                   result: self.result,
+                   stock_size: size,
+                   trump_card: self.stock.first().cloned(),
+                   stock_top,
+                   tricks: self.tricks.len(),
+                   event_count: self.drained_events + self.events.len(),
+                   hash: self.hash}
+    }
+
+    fn push_history(&mut self, entry: UndoEntry) {
+        self.moves.push(entry.mv);
+        self.history.push(entry);
+        self.redo_moves.clear();
+    }
+
+    fn hash_lead_card(&mut self, player: PlayerId, card: Card) {
+        self.hash ^= zobrist::hand(player, card) ^ zobrist::trick(card);
+    }
+
+    fn finish_deal(&mut self, reason: DealEndReason) {
+        if let Some(winner) = self.winner() {
+            self.hash ^= zobrist::winner(winner);
+            let result = match self.public_data.closing {
+                Some(closing) => DealResult::closed(winner, &closing, reason),
+                None => DealResult::new(winner,
+                                        self.get_player(winner.other()),
+                                        reason)
+            };
+            self.result = Some(result);
+            self.events.push(GameEvent::DealOver(result));
+        }
+    }
+
+    fn reveal_marriage_partner(&mut self, player: PlayerId, card: Card) {
+        let partner_rank = if card.rank() == Rank::Ober {
+            Rank::King
+        } else {
+            Rank::Ober
+        };
+
+        let partner = Card::new(card.suit(), partner_rank);
+        self.get_player_mut(player).revealed.push(partner);
+    }
+
+    fn declare_marriage(&mut self, player: PlayerId, suit: Suit) {
+        let points = if suit == self.trump() {
+            40
+        } else {
+            20
+        };
+
+        self.events.push(GameEvent::MarriageDeclared {player, suit, points});
     }
 
     fn deal_if_not_closed_or_empty(&mut self, winner_of_trick: PlayerId)
@@ -293,16 +812,29 @@ impl Game {
         // number of cards in the stock and the stock is not empty.
         let winner_new_card = self.stock.pop().unwrap();
         let loser_new_card = self.stock.pop().unwrap();
+
+        let size = self.stock.len();
+        self.hash ^= zobrist::stock(size + 1, winner_new_card)
+            ^ zobrist::hand(winner_of_trick, winner_new_card)
+            ^ zobrist::stock(size, loser_new_card)
+            ^ zobrist::hand(winner_of_trick.other(), loser_new_card);
         
         {
             let winning_player = self.get_player_mut(winner_of_trick);
-            winning_player.hand.push(winner_new_card);
+            winning_player.hand.insert(winner_new_card);
         }
 
         {
+            let stock_is_empty = self.stock.is_empty();
             let losing_player
                 = self.get_player_mut(winner_of_trick.other());
-            losing_player.hand.push(loser_new_card);
+            losing_player.hand.insert(loser_new_card);
+
+            // The last card of the stock is the face up trump card, so both
+            // players know who took it.
+            if stock_is_empty {
+                losing_player.revealed.push(loser_new_card);
+            }
         }
 
         if winner_of_trick == PlayerId::Player1 {
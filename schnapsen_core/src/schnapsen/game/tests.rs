@@ -1,4 +1,7 @@
 use super::*;
+use schnapsen::deal_result::{DealEndReason, DealResult};
+use schnapsen::event::GameEvent;
+use schnapsen::game_builder::GameBuilder;
 
 #[test]
 fn test_new_with_odd_number_of_cards_fails() {
@@ -12,6 +15,28 @@ fn test_new_with_odd_number_of_cards_fails() {
     assert!(game_option.is_none());
 }
 
+#[test]
+fn test_from_deck_ok() {
+    let deck = generate_deck();
+    let game = Game::from_deck(deck.clone()).unwrap();
+
+    assert_eq!(CardSet::from(&deck[15..]), game.player1.hand);
+    assert_eq!(CardSet::from(&deck[10..15]), game.player2.hand);
+    assert_eq!(&deck[..10], &game.stock[..]);
+    assert_eq!(Some(deck[0]), game.trump_card());
+    assert_eq!(PlayerId::Player1, game.player_on_lead());
+}
+
+#[test]
+fn test_from_deck_invalid() {
+    let mut deck = generate_deck();
+    deck.push(Card::new(Suit::Leaves, Rank::Seven));
+
+    let result = Game::from_deck(deck);
+    assert_eq!(DeckError::IllegalCard(Card::new(Suit::Leaves, Rank::Seven)),
+               result.unwrap_err());
+}
+
 #[test]
 fn test_new_random_game() {
     let game = Game::new_random();
@@ -23,6 +48,35 @@ fn test_new_random_game() {
     assert_eq!(game.stock[0].suit(), game.trump());
 }
 
+#[test]
+fn test_new_seeded_same_seed_same_deal() {
+    let game1 = Game::new_seeded(42);
+    let game2 = Game::new_seeded(42);
+
+    assert_eq!(game1.stock, game2.stock);
+    assert_eq!(game1.player1.hand, game2.player1.hand);
+    assert_eq!(game1.player2.hand, game2.player2.hand);
+}
+
+#[test]
+fn test_new_seeded_different_seeds() {
+    let game1 = Game::new_seeded(1);
+    let game2 = Game::new_seeded(2);
+
+    assert!(game1.stock != game2.stock
+            || game1.player1.hand != game2.player1.hand);
+}
+
+#[test]
+fn test_new_with_rng() {
+    let mut rng = rand::XorShiftRng::new_unseeded();
+    let game = Game::new_with_rng(&mut rng);
+
+    assert_eq!(10, game.stock.len());
+    assert_eq!(5, game.player1.hand.len());
+    assert_eq!(5, game.player2.hand.len());
+}
+
 #[test]
 fn test_trump_card_available() {
     let game = Game::default();
@@ -82,14 +136,14 @@ fn test_close_not_on_lead() {
     let trump_card = stock[0];
     let trump = trump_card.suit();
 
-    let player1 = Player {hand: player1_hand, ..Default::default()};
-    let player2 = Player {hand: player2_hand, ..Default::default()};
+    let player1 = Player {hand: player1_hand.into(), ..Default::default()};
+    let player2 = Player {hand: player2_hand.into(), ..Default::default()};
 
     let public_data = PublicGameData {trump, ..Default::default()};
     let mut game = Game {stock, public_data, player1, player2,
                          ..Default::default()};
 
-    let card1 = game.player1.hand[0];
+    let card1 = game.player1.hand.to_vec()[0];
 
     let first_card_result = game.play_card(card1);
     assert!(first_card_result.is_ok());
@@ -163,8 +217,8 @@ fn test_exchange_trump_not_on_lead() {
     let trump_card = stock[0];
     let trump = trump_card.suit();
 
-    let player1 = Player {hand: player1_hand, ..Default::default()};
-    let player2 = Player {hand: player2_hand, ..Default::default()};
+    let player1 = Player {hand: player1_hand.into(), ..Default::default()};
+    let player2 = Player {hand: player2_hand.into(), ..Default::default()};
 
     let public_data = PublicGameData {trump, ..Default::default()};
     let mut game = Game {stock, public_data, player1, player2, ..Default::default()};
@@ -201,8 +255,8 @@ fn test_exchange_trump_not_having_trump_unter() {
     let trump_card = stock[0];
     let trump = trump_card.suit();
 
-    let player1 = Player {hand: player1_hand, ..Default::default()};
-    let player2 = Player {hand: player2_hand, ..Default::default()};
+    let player1 = Player {hand: player1_hand.into(), ..Default::default()};
+    let player2 = Player {hand: player2_hand.into(), ..Default::default()};
 
     let public_data = PublicGameData {trump, ..Default::default()};
     let mut game = Game {stock, public_data, player1, player2,
@@ -239,8 +293,8 @@ fn test_exchange_trump_not_enough_cards_in_stock() {
     let trump_card = stock[0];
     let trump = trump_card.suit();
 
-    let player1 = Player {hand: player1_hand, ..Default::default()};
-    let player2 = Player {hand: player2_hand, ..Default::default()};
+    let player1 = Player {hand: player1_hand.into(), ..Default::default()};
+    let player2 = Player {hand: player2_hand.into(), ..Default::default()};
 
     let public_data = PublicGameData {trump, ..Default::default()};
     let mut game = Game {stock, public_data, player1, player2,
@@ -278,8 +332,8 @@ fn test_exchange_trump_closed() {
     let trump_card = stock[0];
     let trump = trump_card.suit();
 
-    let player1 = Player {hand: player1_hand, ..Default::default()};
-    let player2 = Player {hand: player2_hand, ..Default::default()};
+    let player1 = Player {hand: player1_hand.into(), ..Default::default()};
+    let player2 = Player {hand: player2_hand.into(), ..Default::default()};
 
     let public_data = PublicGameData {trump, ..Default::default()};
     let mut game = Game {stock, public_data, player1, player2,
@@ -297,7 +351,7 @@ fn test_exchange_trump_closed() {
     assert_eq!(expected_error, result);
 
     assert_eq!(None, game.trump_card());
-    assert!(game.player1.hand.contains(&Card::new(trump, Rank::Unter)));
+    assert!(game.player1.hand.contains(Card::new(trump, Rank::Unter)));
 }
 
 #[test]
@@ -322,8 +376,8 @@ fn test_exchange_trump_ok() {
     let trump_card = stock[0];
     let trump = trump_card.suit();
 
-    let player1 = Player {hand: player1_hand, ..Default::default()};
-    let player2 = Player {hand: player2_hand, ..Default::default()};
+    let player1 = Player {hand: player1_hand.into(), ..Default::default()};
+    let player2 = Player {hand: player2_hand.into(), ..Default::default()};
 
     let public_data = PublicGameData {trump, ..Default::default()};
     let mut game = Game {stock, public_data, player1, player2,
@@ -335,7 +389,7 @@ fn test_exchange_trump_ok() {
     assert!(result.is_ok());
 
     assert_eq!(Card::new(trump, Rank::Unter), game.trump_card().unwrap());
-    assert!(game.player1.hand.contains(&trump_card));
+    assert!(game.player1.hand.contains(trump_card));
 }
 
 #[test]
@@ -356,8 +410,8 @@ fn test_play_card_twenty_not_on_lead() {
 
     let card1 = hand1[0];
 
-    let player1 = Player {hand: hand1, ..Default::default()};
-    let player2 = Player {hand: hand2, ..Default::default()};
+    let player1 = Player {hand: hand1.into(), ..Default::default()};
+    let player2 = Player {hand: hand2.into(), ..Default::default()};
 
     let trump = Suit::Hearts;
     let public_data = PublicGameData {trump, ..Default::default()};
@@ -391,8 +445,8 @@ fn test_play_card_twenty_no_such_cards() {
                      Card::new(Suit::Bells, Rank::Unter),
                      Card::new(Suit::Hearts, Rank::Ten)];
 
-    let player1 = Player {hand: hand1, ..Default::default()};
-    let player2 = Player {hand: hand2, ..Default::default()};
+    let player1 = Player {hand: hand1.into(), ..Default::default()};
+    let player2 = Player {hand: hand2.into(), ..Default::default()};
 
     let trump = Suit::Hearts;
     let public_data = PublicGameData {trump, ..Default::default()};
@@ -424,8 +478,8 @@ fn test_play_card_twenty_suit_is_trump() {
                      Card::new(Suit::Bells, Rank::Unter),
                      Card::new(Suit::Hearts, Rank::Ten)];
 
-    let player1 = Player {hand: hand1, ..Default::default()};
-    let player2 = Player {hand: hand2, ..Default::default()};
+    let player1 = Player {hand: hand1.into(), ..Default::default()};
+    let player2 = Player {hand: hand2.into(), ..Default::default()};
 
     let twenty_card = Card::new(Suit::Bells, Rank::King);
 
@@ -459,8 +513,8 @@ fn test_play_card_twenty_play_wrong_card() {
                      Card::new(Suit::Bells, Rank::Unter),
                      Card::new(Suit::Hearts, Rank::Ten)];
 
-    let player1 = Player {hand: hand1, ..Default::default()};
-    let player2 = Player {hand: hand2, ..Default::default()};
+    let player1 = Player {hand: hand1.into(), ..Default::default()};
+    let player2 = Player {hand: hand2.into(), ..Default::default()};
 
     let trump = Suit::Hearts;
     let public_data = PublicGameData {trump, ..Default::default()};
@@ -491,8 +545,8 @@ fn test_play_card_twenty_ok() {
                      Card::new(Suit::Bells, Rank::Unter),
                      Card::new(Suit::Hearts, Rank::Ten)];
 
-    let player1 = Player {hand: hand1, ..Default::default()};
-    let player2 = Player {hand: hand2, ..Default::default()};
+    let player1 = Player {hand: hand1.into(), ..Default::default()};
+    let player2 = Player {hand: hand2.into(), ..Default::default()};
 
     let trump = Suit::Hearts;
     let public_data = PublicGameData {trump, ..Default::default()};
@@ -526,8 +580,9 @@ fn test_declare_twenty_score_too_low() {
 
     let wins1 = vec![Card::new(Suit::Acorns, Rank::Ace)];
 
-    let player1 = Player {hand: hand1, wins: wins1, ..Default::default()};
-    let player2 = Player {hand: hand2, ..Default::default()};
+    let player1 = Player {hand: hand1.into(), wins: wins1.into(),
+                          ..Default::default()};
+    let player2 = Player {hand: hand2.into(), ..Default::default()};
 
     let trump = Suit::Hearts;
     let public_data = PublicGameData {trump, ..Default::default()};
@@ -565,8 +620,9 @@ fn test_declare_twenty_ok() {
                      Card::new(Suit::Hearts, Rank::Ten),
                      Card::new(Suit::Leaves, Rank::King)];
 
-    let player1 = Player {hand: hand1, wins: wins1, ..Default::default()};
-    let player2 = Player {hand: hand2, ..Default::default()};
+    let player1 = Player {hand: hand1.into(), wins: wins1.into(),
+                          ..Default::default()};
+    let player2 = Player {hand: hand2.into(), ..Default::default()};
 
     let trump = Suit::Hearts;
     let public_data = PublicGameData {trump, ..Default::default()};
@@ -599,8 +655,8 @@ fn test_play_card_forty_not_on_lead() {
 
     let card1 = hand1[0];
 
-    let player1 = Player {hand: hand1, ..Default::default()};
-    let player2 = Player {hand: hand2, ..Default::default()};
+    let player1 = Player {hand: hand1.into(), ..Default::default()};
+    let player2 = Player {hand: hand2.into(), ..Default::default()};
 
     let trump = Suit::Bells;
     let forty_card = Card::new(trump, Rank::King);
@@ -634,8 +690,8 @@ fn test_play_card_forty_no_such_cards() {
                      Card::new(Suit::Bells, Rank::Unter),
                      Card::new(Suit::Hearts, Rank::Ten)];
 
-    let player1 = Player {hand: hand1, ..Default::default()};
-    let player2 = Player {hand: hand2, ..Default::default()};
+    let player1 = Player {hand: hand1.into(), ..Default::default()};
+    let player2 = Player {hand: hand2.into(), ..Default::default()};
 
     let trump = Suit::Bells;
     let forty_card = Card::new(trump, Rank::Ober);
@@ -667,8 +723,8 @@ fn test_play_card_forty_wrong_card() {
                      Card::new(Suit::Bells, Rank::Unter),
                      Card::new(Suit::Hearts, Rank::Ten)];
 
-    let player1 = Player {hand: hand1, ..Default::default()};
-    let player2 = Player {hand: hand2, ..Default::default()};
+    let player1 = Player {hand: hand1.into(), ..Default::default()};
+    let player2 = Player {hand: hand2.into(), ..Default::default()};
 
     let trump = Suit::Bells;
     let forty_card = Card::new(trump, Rank::Ten);
@@ -700,8 +756,9 @@ fn test_declare_forty_too_low() {
     
     let wins1 = vec![Card::new(Suit::Acorns, Rank::Ace)];
 
-    let player1 = Player {hand: hand1, wins: wins1, ..Default::default()};
-    let player2 = Player {hand: hand2, ..Default::default()};
+    let player1 = Player {hand: hand1.into(), wins: wins1.into(),
+                          ..Default::default()};
+    let player2 = Player {hand: hand2.into(), ..Default::default()};
 
     let trump = Suit::Bells;
     let public_data = PublicGameData {trump, ..Default::default()};
@@ -737,8 +794,9 @@ fn test_declare_forty_ok() {
                      Card::new(Suit::Hearts, Rank::Ten),
                      Card::new(Suit::Leaves, Rank::King)];
 
-    let player1 = Player {hand: hand1, wins: wins1, ..Default::default()};
-    let player2 = Player {hand: hand2, ..Default::default()};
+    let player1 = Player {hand: hand1.into(), wins: wins1.into(),
+                          ..Default::default()};
+    let player2 = Player {hand: hand2.into(), ..Default::default()};
 
     let trump = Suit::Bells;
     let public_data = PublicGameData {trump, ..Default::default()};
@@ -766,8 +824,8 @@ fn test_play_card_forty_ok() {
                      Card::new(Suit::Bells, Rank::Unter),
                      Card::new(Suit::Hearts, Rank::Ten)];
 
-    let player1 = Player {hand: hand1, ..Default::default()};
-    let player2 = Player {hand: hand2, ..Default::default()};
+    let player1 = Player {hand: hand1.into(), ..Default::default()};
+    let player2 = Player {hand: hand2.into(), ..Default::default()};
 
     let trump = Suit::Bells;
     let forty_card = Card::new(trump, Rank::King);
@@ -801,8 +859,8 @@ fn test_declare_win_not_on_lead() {
                             Card::new(Suit::Leaves, Rank::King),
                             Card::new(Suit::Leaves, Rank::Ober)];
     
-    let player1 = Player {hand: hand1, ..Default::default()};
-    let player2 = Player {hand: hand2, wins: player2_wins,
+    let player1 = Player {hand: hand1.into(), ..Default::default()};
+    let player2 = Player {hand: hand2.into(), wins: player2_wins.into(),
                           ..Default::default()};
     let mut game = Game {stock, player1, player2, ..Default::default()};
 
@@ -825,7 +883,7 @@ fn declare_win_already_game_over() {
                             Card::new(Suit::Hearts, Rank::Ten),
                             Card::new(Suit::Leaves, Rank::King),
                             Card::new(Suit::Leaves, Rank::Ober)];
-    let player1 = Player {wins: player1_wins, ..Default::default()};
+    let player1 = Player {wins: player1_wins.into(), ..Default::default()};
     let mut game = Game {player1, ..Default::default()};
     
     let first_win_declaration_result = game.declare_win();
@@ -844,7 +902,7 @@ fn declare_win_already_game_over() {
 fn declare_win_not_enough() {
     let player1_wins = vec![Card::new(Suit::Leaves, Rank::Ace),
                             Card::new(Suit::Leaves, Rank::Ten)];
-    let player1 = Player {wins: player1_wins, ..Default::default()};
+    let player1 = Player {wins: player1_wins.into(), ..Default::default()};
     let mut game = Game {player1, ..Default::default()};
     
     let expected_error = Err(ErrorKind::ScoreTooLow(game.player1.score()));
@@ -864,7 +922,7 @@ fn declare_win_ok() {
                             Card::new(Suit::Hearts, Rank::Ten),
                             Card::new(Suit::Leaves, Rank::King),
                             Card::new(Suit::Leaves, Rank::Ober)];
-    let player1 = Player {wins: player1_wins, ..Default::default()};
+    let player1 = Player {wins: player1_wins.into(), ..Default::default()};
     let mut game = Game {player1, ..Default::default()};
 
     let player1_marker = game.player_on_turn();
@@ -888,7 +946,7 @@ fn test_play_game_already_over() {
 
     let player1_hand = vec![Card::new(Suit::Leaves, Rank::Unter)];
     
-    let player1 = Player {hand: player1_hand, wins: player1_wins,
+    let player1 = Player {hand: player1_hand.into(), wins: player1_wins.into(),
                           ..Default::default()};
     let mut game = Game {player1, ..Default::default()};
 
@@ -896,7 +954,7 @@ fn test_play_game_already_over() {
     assert!(declare_win_result.is_ok());
     assert!(game.is_game_over());
 
-    let card = game.player1.hand[0];
+    let card = game.player1.hand.to_vec()[0];
 
     let expected_error = ErrorKind::GameOver;
 
@@ -910,7 +968,8 @@ fn test_play_game_already_over() {
 fn test_play_card_invalid_player1_card() {
     let mut game = Game::default();
 
-    let removed_card = game.player1.hand.pop().unwrap();
+    let removed_card = game.player1.hand.to_vec()[4];
+    game.player1.hand.remove(removed_card);
 
     let expected_error = ErrorKind::NoSuchCardInHand(removed_card);
 
@@ -924,8 +983,9 @@ fn test_play_card_invalid_player1_card() {
 fn test_play_card_invalid_player2_card() {
     let mut game = Game::default();
 
-    let removed_card = game.player2.hand.pop().unwrap();
-    let player1_card = *game.player1.hand.first().unwrap();
+    let removed_card = game.player2.hand.to_vec()[4];
+    game.player2.hand.remove(removed_card);
+    let player1_card = game.player1.hand.iter().next().unwrap();
 
     let first_card_result = game.play_card(player1_card);
     assert!(first_card_result.is_ok());
@@ -942,7 +1002,7 @@ fn test_play_card_invalid_player2_card() {
 pub fn test_play_card_player1_card_ok() {
     let mut game = Game::default();
 
-    let card = *game.player1.hand.first().unwrap();
+    let card = game.player1.hand.iter().next().unwrap();
 
     let player1_marker = game.player_on_turn();
                              
@@ -950,7 +1010,7 @@ pub fn test_play_card_player1_card_ok() {
     assert!(result.is_ok());
 
     assert_eq!(Some(card), game.public_data.first_card_in_trick);
-    assert!(!game.player1.hand.contains(&card));
+    assert!(!game.player1.hand.contains(card));
     assert_eq!(player1_marker.other(), game.player_on_turn());
 }
 
@@ -980,10 +1040,10 @@ fn test_play_card_illegal_suit() {
     let card1 = player1_hand[0];
     let card2 = player2_hand[2];
     
-    let player1 = Player {name: "Player1".to_string(), hand: player1_hand,
-                          ..Default::default()};
-    let player2 = Player {name: "Player2".to_string(), hand: player2_hand,
-                          ..Default::default()};
+    let player1 = Player {name: "Player1".to_string(),
+                          hand: player1_hand.into(), ..Default::default()};
+    let player2 = Player {name: "Player2".to_string(),
+                          hand: player2_hand.into(), ..Default::default()};
 
     let public_data = PublicGameData {trump, ..Default::default()};
     let mut game = Game {
@@ -1028,10 +1088,10 @@ fn test_play_card_stock_depleted_must_be_higher() {
     let card2 = player2_hand[0];
     let card2_takes = player2_hand[1];
     
-    let player1 = Player {name: "Player1".to_string(), hand: player1_hand,
-                          ..Default::default()};
-    let player2 = Player {name: "Player2".to_string(), hand: player2_hand,
-                          ..Default::default()};
+    let player1 = Player {name: "Player1".to_string(),
+                          hand: player1_hand.into(), ..Default::default()};
+    let player2 = Player {name: "Player2".to_string(),
+                          hand: player2_hand.into(), ..Default::default()};
 
     let public_data = PublicGameData {trump, ..Default::default()};
     let mut game = Game {
@@ -1069,10 +1129,10 @@ fn test_play_card_stock_depleted_must_use_trump() {
     let card1 = player1_hand[0];
     let card2 = player2_hand[0];
     
-    let player1 = Player {name: "Player1".to_string(), hand: player1_hand,
-                          ..Default::default()};
-    let player2 = Player {name: "Player2".to_string(), hand: player2_hand,
-                          ..Default::default()};
+    let player1 = Player {name: "Player1".to_string(),
+                          hand: player1_hand.into(), ..Default::default()};
+    let player2 = Player {name: "Player2".to_string(),
+                          hand: player2_hand.into(), ..Default::default()};
 
     let public_data = PublicGameData {trump, ..Default::default()};
     let mut game = Game {
@@ -1138,10 +1198,10 @@ fn test_play_card_player2_card_ok_template(should_be_closed: bool) {
         Card{suit: Suit::Bells, rank: Rank::Ace},
     ];
 
-    let player1 = Player {name: "Player1".to_string(), hand: player1_hand,
-                          ..Default::default()};
-    let player2 = Player {name: "Player2".to_string(), hand: player2_hand,
-                          ..Default::default()};
+    let player1 = Player {name: "Player1".to_string(),
+                          hand: player1_hand.into(), ..Default::default()};
+    let player2 = Player {name: "Player2".to_string(),
+                          hand: player2_hand.into(), ..Default::default()};
 
     let public_data = PublicGameData {trump, ..Default::default()};
     let mut game = Game {
@@ -1157,8 +1217,8 @@ fn test_play_card_player2_card_ok_template(should_be_closed: bool) {
         assert!(game.is_closed());
     }
 
-    let card1 = game.player1.hand[4];
-    let card2 = game.player2.hand[4];
+    let card1 = Card::new(Suit::Bells, Rank::Ten);
+    let card2 = Card::new(Suit::Bells, Rank::Ace);
 
     let first_card_result = game.play_card(card1);
     assert_eq!(Ok(None), first_card_result);
@@ -1175,8 +1235,8 @@ fn test_play_card_player2_card_ok_template(should_be_closed: bool) {
     let result = game.play_card(card2);
     assert_eq!(Ok(expected_cards), result);
 
-    assert!(!game.player1.hand.contains(&card1));
-    assert!(!game.player2.hand.contains(&card2));
+    assert!(!game.player1.hand.contains(card1));
+    assert!(!game.player2.hand.contains(card2));
 
     let cards_less = if should_be_closed {1} else {0};
     assert_eq!(5 - cards_less, game.player1.hand.len());
@@ -1185,8 +1245,8 @@ fn test_play_card_player2_card_ok_template(should_be_closed: bool) {
     let dealed_cards = if should_be_closed {0} else {2};
     assert_eq!(original_stock_size - dealed_cards, game.stock.len());
     
-    assert!(game.player2.wins.contains(&card1));
-    assert!(game.player2.wins.contains(&card2));
+    assert!(game.player2.wins.contains(card1));
+    assert!(game.player2.wins.contains(card2));
     assert_eq!(player2_marker, game.player_on_lead());
     assert_eq!(player2_marker, game.player_on_turn());
 }
@@ -1201,8 +1261,8 @@ fn test_play_not_last_cards_stock_depleted_no_game_over() {
     let hand1 = vec![card1, Card::new(Suit::Leaves, Rank::Ober)];
     let hand2 = vec![card2, Card::new(Suit::Leaves, Rank::King)];
 
-    let player1 = Player {hand: hand1, ..Default::default()};
-    let player2 = Player {hand: hand2, ..Default::default()};
+    let player1 = Player {hand: hand1.into(), ..Default::default()};
+    let player2 = Player {hand: hand2.into(), ..Default::default()};
 
     let trump = Suit::Leaves;
     let public_data = PublicGameData {trump, ..Default::default()};
@@ -1234,8 +1294,8 @@ fn test_play_last_cards_game_over() {
     let hand1 = vec![card1];
     let hand2 = vec![card2];
 
-    let player1 = Player {hand: hand1, ..Default::default()};
-    let player2 = Player {hand: hand2, ..Default::default()};
+    let player1 = Player {hand: hand1.into(), ..Default::default()};
+    let player2 = Player {hand: hand2.into(), ..Default::default()};
 
     let trump = Suit::Leaves;
     let public_data = PublicGameData {trump, ..Default::default()};
@@ -1256,3 +1316,799 @@ fn test_play_last_cards_game_over() {
     assert!(game.is_game_over());
     assert_eq!(Some(player2_marker), game.winner());
 }
+
+#[test]
+fn test_deal_result_none_while_running() {
+    let game = Game::default();
+
+    assert_eq!(None, game.deal_result());
+}
+
+#[test]
+fn test_deal_result_declared_win() {
+    let player1_wins = vec![Card::new(Suit::Leaves, Rank::Ace),
+                            Card::new(Suit::Leaves, Rank::Ten),
+                            Card::new(Suit::Bells, Rank::Ace),
+                            Card::new(Suit::Bells, Rank::Ten),
+                            Card::new(Suit::Hearts, Rank::Ace),
+                            Card::new(Suit::Hearts, Rank::Ten),
+                            Card::new(Suit::Leaves, Rank::King),
+                            Card::new(Suit::Leaves, Rank::Ober)];
+    let player2_wins = vec![Card::new(Suit::Acorns, Rank::Ace),
+                            Card::new(Suit::Acorns, Rank::Unter)];
+    let player1 = Player {wins: player1_wins.into(), ..Default::default()};
+    let player2 = Player {wins: player2_wins.into(), ..Default::default()};
+    let mut game = Game {player1, player2, ..Default::default()};
+
+    let result = game.declare_win();
+    assert!(result.is_ok());
+
+    let expected_result = DealResult {winner: PlayerId::Player1,
+                                      game_points: 2,
+                                      reason: DealEndReason::DeclaredWin};
+    assert_eq!(Some(expected_result), game.deal_result());
+}
+
+#[test]
+fn test_deal_result_last_trick() {
+    let stock = Vec::new();
+
+    let card1 = Card::new(Suit::Leaves, Rank::Ten);
+    let card2 = Card::new(Suit::Leaves, Rank::Ace);
+
+    let player1_wins = vec![Card::new(Suit::Acorns, Rank::Ace),
+                            Card::new(Suit::Acorns, Rank::Ten),
+                            Card::new(Suit::Bells, Rank::Ace),
+                            Card::new(Suit::Bells, Rank::Unter)];
+
+    let player1 = Player {hand: vec![card1].into(), wins: player1_wins.into(),
+                          ..Default::default()};
+    let player2 = Player {hand: vec![card2].into(), ..Default::default()};
+
+    let trump = Suit::Leaves;
+    let public_data = PublicGameData {trump, ..Default::default()};
+    let mut game = Game {stock, public_data, player1, player2,
+                         ..Default::default()};
+
+    assert!(game.play_card(card1).is_ok());
+    assert!(game.play_card(card2).is_ok());
+
+    let expected_result = DealResult {winner: PlayerId::Player2,
+                                      game_points: 1,
+                                      reason: DealEndReason::LastTrick};
+    assert_eq!(Some(expected_result), game.deal_result());
+}
+
+#[test]
+fn test_close_records_closing() {
+    let mut game = Game::default();
+
+    let player2_wins = vec![Card::new(Suit::Leaves, Rank::Ace),
+                            Card::new(Suit::Leaves, Rank::Ten)];
+    game.player2.wins = player2_wins.into();
+
+    assert_eq!(None, game.closing());
+    assert!(game.close().is_ok());
+
+    let expected_closing = Closing {closer: PlayerId::Player1,
+                                    stock_size: 10,
+                                    opponent_score: 21,
+                                    opponent_has_trick: true};
+    assert_eq!(Some(expected_closing), game.closing());
+}
+
+fn closed_game_with_last_cards(player2_wins: Vec<Card>) -> Game {
+    let stock = vec![Card::new(Suit::Hearts, Rank::Unter),
+                     Card::new(Suit::Hearts, Rank::Ober),
+                     Card::new(Suit::Hearts, Rank::King),
+                     Card::new(Suit::Hearts, Rank::Ten)];
+
+    let player1_wins = vec![Card::new(Suit::Acorns, Rank::Ace),
+                            Card::new(Suit::Acorns, Rank::Ten),
+                            Card::new(Suit::Bells, Rank::Ace),
+                            Card::new(Suit::Bells, Rank::Ten),
+                            Card::new(Suit::Leaves, Rank::Ten)];
+
+    let hand1 = vec![Card::new(Suit::Hearts, Rank::Ace)];
+    let hand2 = vec![Card::new(Suit::Leaves, Rank::Unter)];
+
+    let player1 = Player {hand: hand1.into(), wins: player1_wins.into(),
+                          ..Default::default()};
+    let player2 = Player {hand: hand2.into(), wins: player2_wins.into(),
+                          ..Default::default()};
+
+    let trump = Suit::Hearts;
+    let public_data = PublicGameData {trump, ..Default::default()};
+    let mut game = Game {stock, public_data, player1, player2,
+                         ..Default::default()};
+
+    assert!(game.close().is_ok());
+    game
+}
+
+#[test]
+fn test_closer_fails_last_trick() {
+    let mut game = closed_game_with_last_cards(Vec::new());
+
+    assert!(game.play_card(Card::new(Suit::Hearts, Rank::Ace)).is_ok());
+    assert!(game.play_card(Card::new(Suit::Leaves, Rank::Unter)).is_ok());
+
+    // Player1 has won the last trick but has not reached 66.
+    assert!(game.get_player1().score() < 66);
+    assert_eq!(Some(PlayerId::Player2), game.winner());
+
+    let expected_result = DealResult {winner: PlayerId::Player2,
+                                      game_points: 3,
+                                      reason: DealEndReason::CloserFailed};
+    assert_eq!(Some(expected_result), game.deal_result());
+}
+
+#[test]
+fn test_closer_fails_opponent_had_trick() {
+    let player2_wins = vec![Card::new(Suit::Leaves, Rank::Ace),
+                            Card::new(Suit::Leaves, Rank::King),
+                            Card::new(Suit::Acorns, Rank::King),
+                            Card::new(Suit::Acorns, Rank::Ober),
+                            Card::new(Suit::Bells, Rank::King),
+                            Card::new(Suit::Bells, Rank::Ober)];
+    let mut game = closed_game_with_last_cards(player2_wins);
+
+    assert!(game.play_card(Card::new(Suit::Hearts, Rank::Ace)).is_ok());
+    assert!(game.play_card(Card::new(Suit::Leaves, Rank::Unter)).is_ok());
+
+    let expected_result = DealResult {winner: PlayerId::Player2,
+                                      game_points: 2,
+                                      reason: DealEndReason::CloserFailed};
+    assert_eq!(Some(expected_result), game.deal_result());
+}
+
+#[test]
+fn test_closer_reaches_66() {
+    let stock = vec![Card::new(Suit::Hearts, Rank::Unter),
+                     Card::new(Suit::Hearts, Rank::Ober),
+                     Card::new(Suit::Hearts, Rank::King),
+                     Card::new(Suit::Hearts, Rank::Ten)];
+
+    let player1_wins = vec![Card::new(Suit::Acorns, Rank::Ace),
+                            Card::new(Suit::Acorns, Rank::Ten),
+                            Card::new(Suit::Bells, Rank::Ace),
+                            Card::new(Suit::Bells, Rank::Ten),
+                            Card::new(Suit::Leaves, Rank::Ten)];
+
+    let hand1 = vec![Card::new(Suit::Hearts, Rank::Ace),
+                     Card::new(Suit::Leaves, Rank::Ace)];
+    let hand2 = vec![Card::new(Suit::Leaves, Rank::Unter),
+                     Card::new(Suit::Leaves, Rank::King)];
+
+    let player1 = Player {hand: hand1.into(), wins: player1_wins.into(),
+                          ..Default::default()};
+    let player2 = Player {hand: hand2.into(), ..Default::default()};
+
+    let trump = Suit::Hearts;
+    let public_data = PublicGameData {trump, ..Default::default()};
+    let mut game = Game {stock, public_data, player1, player2,
+                         ..Default::default()};
+
+    assert!(game.close().is_ok());
+
+    assert!(game.play_card(Card::new(Suit::Leaves, Rank::Ace)).is_ok());
+    assert!(game.play_card(Card::new(Suit::Leaves, Rank::King)).is_ok());
+
+    assert!(!game.is_game_over());
+    assert!(game.declare_win().is_ok());
+
+    // Player2 had no trick at the time of closing.
+    let expected_result = DealResult {winner: PlayerId::Player1,
+                                      game_points: 3,
+                                      reason: DealEndReason::DeclaredWin};
+    assert_eq!(Some(expected_result), game.deal_result());
+}
+
+#[test]
+fn test_declare_win_marriages_without_trick() {
+    let player1 = Player {twenties: vec![Suit::Bells, Suit::Acorns],
+                          forty: Some(Suit::Hearts),
+                          ..Default::default()};
+    let mut game = Game {player1, ..Default::default()};
+
+    let expected_error = Err(ErrorKind::MarriageWithoutTrick);
+    assert_eq!(expected_error, game.can_declare_win());
+
+    let result = game.declare_win();
+    assert_eq!(expected_error, result);
+    assert!(!game.is_game_over());
+}
+
+#[test]
+fn test_declare_forty_marriage_without_trick() {
+    let stock = Vec::new();
+    let hand1 = vec![Card::new(Suit::Leaves, Rank::Ace),
+                     Card::new(Suit::Leaves, Rank::Ten),
+                     Card::new(Suit::Bells, Rank::Ober),
+                     Card::new(Suit::Bells, Rank::King),
+                     Card::new(Suit::Hearts, Rank::Ober)];
+
+    let hand2 = vec![Card::new(Suit::Leaves, Rank::Ober),
+                     Card::new(Suit::Leaves, Rank::Unter),
+                     Card::new(Suit::Bells, Rank::Ace),
+                     Card::new(Suit::Bells, Rank::Unter),
+                     Card::new(Suit::Hearts, Rank::Unter)];
+
+    let player1 = Player {hand: hand1.into(), twenties: vec![Suit::Acorns],
+                          ..Default::default()};
+    let player2 = Player {hand: hand2.into(), ..Default::default()};
+
+    let trump = Suit::Bells;
+    let public_data = PublicGameData {trump, ..Default::default()};
+    let mut game = Game {stock, public_data, player1, player2,
+                         ..Default::default()};
+
+    // The forty and the twenty would add up to 60, but Player1 has not won
+    // a trick yet.
+    assert_eq!(0, game.get_player1().score());
+    assert_eq!(Err(ErrorKind::ScoreTooLow(0)), game.can_declare_forty_win());
+
+    game.player1.twenties.push(Suit::Hearts);
+    let expected_error = Err(ErrorKind::MarriageWithoutTrick);
+    assert_eq!(expected_error, game.can_declare_forty_win());
+
+    let result = game.declare_forty_win();
+    assert_eq!(expected_error, result);
+}
+
+#[test]
+fn test_legal_moves_default_game() {
+    let game = Game::default();
+
+    let leaves_ober = Card::new(Suit::Leaves, Rank::Ober);
+    let leaves_king = Card::new(Suit::Leaves, Rank::King);
+
+    let mut expected_moves: Vec<Move> = game.player1.hand.iter()
+        .map(Move::PlayCard)
+        .collect();
+    expected_moves.extend_from_slice(&[Move::PlayTwenty(leaves_ober),
+                                       Move::PlayTwenty(leaves_king),
+                                       Move::Close]);
+
+    assert_eq!(expected_moves, game.legal_moves());
+}
+
+#[test]
+fn test_legal_moves_second_card_in_endgame() {
+    let stock = Vec::new();
+
+    let card1 = Card::new(Suit::Acorns, Rank::Ten);
+    let hand1 = vec![card1, Card::new(Suit::Leaves, Rank::Ober)];
+    let hand2 = vec![Card::new(Suit::Acorns, Rank::Ace),
+                     Card::new(Suit::Acorns, Rank::Unter),
+                     Card::new(Suit::Hearts, Rank::Ober)];
+
+    let player1 = Player {hand: hand1.into(), ..Default::default()};
+    let player2 = Player {hand: hand2.into(), ..Default::default()};
+
+    let public_data = PublicGameData {trump: Suit::Hearts,
+                                      ..Default::default()};
+    let mut game = Game {stock, public_data, player1, player2,
+                         ..Default::default()};
+
+    assert!(game.apply(Move::PlayCard(card1)).is_ok());
+
+    let expected_moves = vec![
+        Move::PlayCard(Card::new(Suit::Acorns, Rank::Ace))];
+    assert_eq!(expected_moves, game.legal_moves());
+}
+
+#[test]
+fn test_legal_moves_game_over() {
+    let player1_wins = vec![Card::new(Suit::Leaves, Rank::Ace),
+                            Card::new(Suit::Leaves, Rank::Ten),
+                            Card::new(Suit::Bells, Rank::Ace),
+                            Card::new(Suit::Bells, Rank::Ten),
+                            Card::new(Suit::Hearts, Rank::Ace),
+                            Card::new(Suit::Hearts, Rank::Ten),
+                            Card::new(Suit::Leaves, Rank::King),
+                            Card::new(Suit::Leaves, Rank::Ober)];
+    let player1 = Player {wins: player1_wins.into(), ..Default::default()};
+    let mut game = Game {player1, ..Default::default()};
+
+    assert!(game.legal_moves().contains(&Move::DeclareWin));
+    assert!(game.apply(Move::DeclareWin).is_ok());
+
+    assert!(game.legal_moves().is_empty());
+    assert_eq!(Err(ErrorKind::GameOver), game.can_apply(Move::DeclareWin));
+}
+
+#[test]
+fn test_apply_error() {
+    let mut game = Game::default();
+
+    let card = game.player2.hand.to_vec()[0];
+    let expected_error = Err(ErrorKind::NoSuchCardInHand(card));
+
+    assert_eq!(expected_error, game.can_apply(Move::PlayCard(card)));
+    assert_eq!(expected_error, game.apply(Move::PlayCard(card)));
+    assert_eq!(Err(ErrorKind::NoSuchCardInHand(
+        Card::new(Suit::Hearts, Rank::Unter))),
+               game.apply(Move::ExchangeTrump));
+}
+
+#[test]
+fn test_apply_legal_moves_until_game_over() {
+    for seed in 0..20 {
+        let mut game = Game::new_seeded(seed);
+
+        while !game.is_game_over() {
+            let moves = game.legal_moves();
+            assert!(!moves.is_empty());
+
+            let mv = moves[seed as usize % moves.len()];
+            assert!(game.apply(mv).is_ok());
+        }
+
+        assert!(game.deal_result().is_some());
+    }
+}
+
+#[test]
+fn test_events_trick_and_deal() {
+    let mut game = Game::default();
+
+    let card1 = Card::new(Suit::Leaves, Rank::Ace);
+    let card2 = Card::new(Suit::Acorns, Rank::Ace);
+
+    assert!(game.play_card(card1).is_ok());
+    assert_eq!(&[GameEvent::CardPlayed {player: PlayerId::Player1,
+                                        card: card1}],
+               game.events());
+
+    let dealt_cards = game.play_card(card2).unwrap().unwrap();
+
+    let expected_events = vec![
+        GameEvent::CardPlayed {player: PlayerId::Player1, card: card1},
+        GameEvent::CardPlayed {player: PlayerId::Player2, card: card2},
+        GameEvent::TrickWon {winner: PlayerId::Player1, lead_card: card1,
+                             reply_card: card2, points: 22},
+        GameEvent::CardsDealt {player1_card: dealt_cards.0,
+                               player2_card: dealt_cards.1}
+    ];
+    assert_eq!(expected_events, game.drain_events());
+    assert!(game.events().is_empty());
+}
+
+#[test]
+fn test_events_close_and_exchange_trump() {
+    let stock = vec![Card::new(Suit::Hearts, Rank::Ace),
+                     Card::new(Suit::Hearts, Rank::King),
+                     Card::new(Suit::Hearts, Rank::Ten),
+                     Card::new(Suit::Hearts, Rank::Ober)];
+
+    let player1_hand = vec![Card::new(Suit::Hearts, Rank::Unter)];
+    let player1 = Player {hand: player1_hand.into(), ..Default::default()};
+
+    let public_data = PublicGameData {trump: Suit::Hearts,
+                                      ..Default::default()};
+    let mut game = Game {stock, public_data, player1, ..Default::default()};
+
+    assert!(game.exchange_trump().is_ok());
+    assert!(game.close().is_ok());
+
+    let expected_events = vec![
+        GameEvent::TrumpExchanged {player: PlayerId::Player1,
+                                   trump_card: Card::new(Suit::Hearts,
+                                                         Rank::Ace)},
+        GameEvent::Closed {player: PlayerId::Player1}
+    ];
+    assert_eq!(expected_events, game.drain_events());
+}
+
+#[test]
+fn test_events_failed_action() {
+    let mut game = Game::default();
+
+    assert!(game.exchange_trump().is_err());
+    assert!(game.declare_win().is_err());
+    assert!(game.events().is_empty());
+}
+
+#[test]
+fn test_events_forty_declare_win() {
+    let stock = Vec::new();
+    let hand1 = vec![Card::new(Suit::Bells, Rank::Ober),
+                     Card::new(Suit::Bells, Rank::King)];
+    let hand2 = vec![Card::new(Suit::Leaves, Rank::Ober),
+                     Card::new(Suit::Leaves, Rank::Unter)];
+
+    let wins1 = vec![Card::new(Suit::Acorns, Rank::Ace),
+                     Card::new(Suit::Acorns, Rank::Ten),
+                     Card::new(Suit::Hearts, Rank::Ace),
+                     Card::new(Suit::Hearts, Rank::Unter)];
+
+    let player1 = Player {hand: hand1.into(), wins: wins1.into(),
+                          ..Default::default()};
+    let player2 = Player {hand: hand2.into(), ..Default::default()};
+
+    let trump = Suit::Bells;
+    let public_data = PublicGameData {trump, ..Default::default()};
+    let mut game = Game {stock, public_data, player1, player2,
+                         ..Default::default()};
+
+    assert!(game.declare_forty_win().is_ok());
+
+    let expected_result = game.deal_result().unwrap();
+    let expected_events = vec![
+        GameEvent::MarriageDeclared {player: PlayerId::Player1, suit: trump,
+                                     points: 40},
+        GameEvent::WinDeclared {player: PlayerId::Player1},
+        GameEvent::DealOver(expected_result)
+    ];
+    assert_eq!(expected_events, game.drain_events());
+}
+
+#[test]
+fn test_events_play_twenty() {
+    let mut game = Game::default();
+
+    let card = Card::new(Suit::Leaves, Rank::King);
+    assert!(game.play_card_twenty(card).is_ok());
+
+    let expected_events = vec![
+        GameEvent::MarriageDeclared {player: PlayerId::Player1,
+                                     suit: Suit::Leaves, points: 20},
+        GameEvent::CardPlayed {player: PlayerId::Player1, card}
+    ];
+    assert_eq!(expected_events, game.drain_events());
+}
+
+fn assert_hash_after_moves(seed: u64, choose: &dyn Fn(&[Move], usize) -> Move) {
+    let mut game = Game::new_seeded(seed);
+    let mut step = 0;
+
+    while !game.is_game_over() {
+        let moves = game.legal_moves();
+        assert!(game.apply(choose(&moves, step)).is_ok());
+        assert_eq!(game.compute_hash(), game.zobrist_hash());
+
+        let mut rehashed = game.clone();
+        rehashed.rehash();
+        assert_eq!(rehashed.zobrist_hash(), game.zobrist_hash());
+        step += 1;
+    }
+}
+
+#[test]
+fn test_zobrist_hash_is_updated_incrementally() {
+    for seed in 0..30 {
+        assert_hash_after_moves(seed, &|moves, step| {
+            moves[(seed as usize + step * 7) % moves.len()]
+        });
+
+        // The special moves come last, so this closes, exchanges the trump
+        // and declares marriages whenever possible.
+        assert_hash_after_moves(seed, &|moves, _| *moves.last().unwrap());
+
+        // Declares a win with a marriage as soon as possible.
+        assert_hash_after_moves(seed, &|moves, step| {
+            moves.iter().cloned()
+                .find(|&mv| matches!(mv, Move::DeclareTwentyWin(_)
+                                     | Move::DeclareFortyWin))
+                .unwrap_or(moves[(seed as usize + step * 3) % moves.len()])
+        });
+    }
+}
+
+fn marriage_win_game(trump: Suit) -> Game {
+    let leaves = CardSet::suit(Suit::Leaves);
+    let hand1 = vec![Card::new(Suit::Leaves, Rank::King),
+                     Card::new(Suit::Leaves, Rank::Ober)];
+    let hand2 = vec![Card::new(Suit::Leaves, Rank::Unter),
+                     Card::new(Suit::Acorns, Rank::Unter)];
+    let wins1 = CardSet::suit(Suit::Bells).union(CardSet::suit(Suit::Hearts));
+    let wins2 = leaves.union(CardSet::suit(Suit::Acorns))
+        .difference(CardSet::from(&hand1[..]))
+        .difference(CardSet::from(&hand2[..]));
+
+    GameBuilder::new()
+        .hand(PlayerId::Player1, hand1)
+        .hand(PlayerId::Player2, hand2)
+        .wins(PlayerId::Player1, wins1.to_vec())
+        .wins(PlayerId::Player2, wins2.to_vec())
+        .trump(trump)
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn test_zobrist_hash_after_marriage_win() {
+    let mut game = marriage_win_game(Suit::Acorns);
+    assert!(game.declare_twenty_win(Suit::Leaves).is_ok());
+    assert_eq!(game.compute_hash(), game.zobrist_hash());
+
+    let mut game = marriage_win_game(Suit::Leaves);
+    assert!(game.declare_forty_win().is_ok());
+    assert_eq!(game.compute_hash(), game.zobrist_hash());
+}
+
+#[test]
+fn test_zobrist_hash_stable() {
+    let game = Game::default();
+
+    assert_eq!(game.compute_hash(), game.zobrist_hash());
+    assert_eq!(Game::default().zobrist_hash(), game.zobrist_hash());
+    assert_ne!(Game::new_seeded(1).zobrist_hash(), game.zobrist_hash());
+}
+
+#[test]
+fn test_game_eq_transposition() {
+    // Both trick orders lead to the same position.
+    let leaves_unter = Card::new(Suit::Leaves, Rank::Unter);
+    let leaves_ober = Card::new(Suit::Leaves, Rank::Ober);
+    let acorns_unter = Card::new(Suit::Acorns, Rank::Unter);
+    let acorns_ober = Card::new(Suit::Acorns, Rank::Ober);
+
+    let mut game1 = Game::default();
+    let mut game2 = Game::default();
+    assert_eq!(game1, game2);
+
+    game1.player1.name = "Someone".to_string();
+    assert_eq!(game1, game2);
+
+    assert!(game1.play_card(leaves_unter).is_ok());
+    assert_ne!(game1, game2);
+    assert_ne!(game1.zobrist_hash(), game2.zobrist_hash());
+
+    assert!(game1.play_card(acorns_unter).is_ok());
+    assert!(game1.play_card(leaves_ober).is_ok());
+    assert!(game1.play_card(acorns_ober).is_ok());
+
+    assert!(game2.play_card(leaves_ober).is_ok());
+    assert!(game2.play_card(acorns_ober).is_ok());
+    assert!(game2.play_card(leaves_unter).is_ok());
+    assert!(game2.play_card(acorns_unter).is_ok());
+
+    assert_eq!(game1, game2);
+    assert_eq!(game1.zobrist_hash(), game2.zobrist_hash());
+
+    let mut set = ::std::collections::HashSet::new();
+    set.insert(game1);
+    assert!(set.contains(&game2));
+}
+
+#[test]
+fn test_zobrist_hash_after_deserialization() {
+    let mut game = Game::new_seeded(7);
+    let mv = game.legal_moves()[0];
+    assert!(game.apply(mv).is_ok());
+
+    let json = ::serde_json::to_string(&game).unwrap();
+    let deserialized: Game = ::serde_json::from_str(&json).unwrap();
+
+    assert_eq!(game, deserialized);
+    assert_eq!(game.zobrist_hash(), deserialized.zobrist_hash());
+
+    let mut set = ::std::collections::HashSet::new();
+    set.insert(game);
+    assert!(set.contains(&deserialized));
+}
+
+fn assert_same_state(expected: &Game, game: &Game) {
+    assert_eq!(expected, game);
+    assert_eq!(expected.stock, game.stock);
+    assert_eq!(expected.player1.revealed, game.player1.revealed);
+    assert_eq!(expected.player2.revealed, game.player2.revealed);
+    assert_eq!(expected.player1.twenties, game.player1.twenties);
+    assert_eq!(expected.player2.twenties, game.player2.twenties);
+    assert_eq!(expected.deal_result(), game.deal_result());
+    assert_eq!(expected.events(), game.events());
+    assert_eq!(expected.moves(), game.moves());
+    assert_eq!(expected.tricks(), game.tricks());
+    assert_eq!(expected.zobrist_hash(), game.zobrist_hash());
+}
+
+fn assert_undo_redo(seed: u64, choose: &dyn Fn(&[Move], usize) -> Move) {
+    let mut game = Game::new_seeded(seed);
+    let mut positions = vec![game.clone()];
+
+    while !game.is_game_over() {
+        let moves = game.legal_moves();
+        assert!(game.apply(choose(&moves, positions.len())).is_ok());
+        positions.push(game.clone());
+    }
+
+    for position in positions.iter().rev().skip(1) {
+        assert!(game.undo().is_some());
+        assert_same_state(position, &game);
+    }
+
+    assert!(!game.can_undo());
+    assert_eq!(None, game.undo());
+
+    for position in positions.iter().skip(1) {
+        assert!(game.redo().is_some());
+        assert_same_state(position, &game);
+    }
+
+    assert!(!game.can_redo());
+    assert_eq!(None, game.redo());
+}
+
+#[test]
+fn test_undo_redo_whole_deal() {
+    for seed in 0..20 {
+        assert_undo_redo(seed, &|moves, step| {
+            moves[(seed as usize + step * 7) % moves.len()]
+        });
+
+        // Closes, exchanges the trump and declares marriages whenever
+        // possible.
+        assert_undo_redo(seed, &|moves, _| *moves.last().unwrap());
+    }
+}
+
+#[test]
+fn test_undo_exchange_trump() {
+    let mut game = Game::default();
+    let before = game.clone();
+
+    let hearts_unter = Card::new(Suit::Hearts, Rank::Unter);
+    game.player1.hand.remove(Card::new(Suit::Leaves, Rank::Unter));
+    game.player1.hand.insert(hearts_unter);
+    game.stock[0] = Card::new(Suit::Hearts, Rank::Ace);
+    game.rehash();
+    let before_exchange = game.clone();
+
+    assert!(game.exchange_trump().is_ok());
+    assert_eq!(Some(hearts_unter), game.trump_card());
+
+    assert_eq!(Some(Move::ExchangeTrump), game.undo());
+    assert_same_state(&before_exchange, &game);
+    assert_ne!(before, game);
+}
+
+#[test]
+fn test_undo_twenty() {
+    let mut game = Game::default();
+    let before = game.clone();
+
+    let leaves_king = Card::new(Suit::Leaves, Rank::King);
+    assert!(game.play_card_twenty(leaves_king).is_ok());
+    assert_eq!(vec![Suit::Leaves], game.player1.twenties);
+    assert_eq!(vec![Card::new(Suit::Leaves, Rank::Ober)],
+               game.player1.revealed);
+
+    assert_eq!(Some(Move::PlayTwenty(leaves_king)), game.undo());
+    assert_same_state(&before, &game);
+    assert!(game.player1.twenties.is_empty());
+    assert!(game.player1.revealed.is_empty());
+}
+
+#[test]
+fn test_undo_declared_win() {
+    let mut game = Game::new_seeded(4);
+
+    while game.can_declare_win().is_err() && !game.is_game_over() {
+        let mv = game.legal_moves()[0];
+        assert!(game.apply(mv).is_ok());
+    }
+
+    assert!(!game.is_game_over());
+
+    let before = game.clone();
+    assert!(game.declare_win().is_ok());
+    assert!(game.deal_result().is_some());
+
+    assert_eq!(Some(Move::DeclareWin), game.undo());
+    assert_same_state(&before, &game);
+    assert_eq!(None, game.deal_result());
+    assert_eq!(None, game.winner());
+
+    assert_eq!(Some(Move::DeclareWin), game.redo());
+    assert!(game.deal_result().is_some());
+}
+
+#[test]
+fn test_new_move_clears_redo() {
+    let mut game = Game::default();
+
+    assert!(game.close().is_ok());
+    assert_eq!(Some(Move::Close), game.undo());
+    assert!(game.can_redo());
+
+    let card = Card::new(Suit::Leaves, Rank::Ace);
+    assert!(game.play_card(card).is_ok());
+    assert!(!game.can_redo());
+    assert_eq!(None, game.redo());
+    assert_eq!(&[Move::PlayCard(card)], game.moves());
+}
+
+#[test]
+fn test_undo_keeps_drained_events() {
+    let mut game = Game::default();
+
+    let card = Card::new(Suit::Leaves, Rank::Ace);
+    assert!(game.play_card(card).is_ok());
+    assert_eq!(1, game.drain_events().len());
+
+    assert!(game.play_card(Card::new(Suit::Acorns, Rank::Ace)).is_ok());
+    assert!(!game.events().is_empty());
+
+    assert!(game.undo().is_some());
+    assert!(game.events().is_empty());
+
+    assert!(game.undo().is_some());
+    assert!(game.events().is_empty());
+    assert!(game.player1.hand.contains(card));
+}
+
+#[test]
+fn test_trick_history() {
+    let mut game = Game::default();
+    assert_eq!(None, game.last_trick());
+
+    let leaves_ace = Card::new(Suit::Leaves, Rank::Ace);
+    let acorns_ace = Card::new(Suit::Acorns, Rank::Ace);
+    assert!(game.play_card(leaves_ace).is_ok());
+    assert_eq!(None, game.last_trick());
+    assert!(game.play_card(acorns_ace).is_ok());
+
+    let first = Trick {number: 1, leader: PlayerId::Player1,
+                       lead_card: leaves_ace, reply_card: acorns_ace,
+                       winner: PlayerId::Player1, stock_size: 10};
+    assert_eq!(Some(&first), game.last_trick());
+    assert_eq!(8, game.stock_size());
+
+    let leaves_ten = Card::new(Suit::Leaves, Rank::Ten);
+    let acorns_ten = Card::new(Suit::Acorns, Rank::Ten);
+    assert!(game.play_card(leaves_ten).is_ok());
+    assert!(game.play_card(acorns_ten).is_ok());
+
+    let second = Trick {number: 2, leader: PlayerId::Player1,
+                        lead_card: leaves_ten, reply_card: acorns_ten,
+                        winner: PlayerId::Player1, stock_size: 8};
+    assert_eq!(&[first, second], game.tricks());
+
+    let view = game.view_for(PlayerId::Player2);
+    assert_eq!(Some(second), view.last_trick);
+
+    assert!(game.undo().is_some());
+    assert_eq!(&[first], game.tricks());
+}
+
+#[test]
+fn test_trick_history_matches_wins() {
+    for seed in 0..10 {
+        let mut game = Game::new_seeded(seed);
+
+        while !game.is_game_over() {
+            let moves = game.legal_moves();
+            assert!(game.apply(moves[seed as usize % moves.len()]).is_ok());
+        }
+
+        for &player in [PlayerId::Player1, PlayerId::Player2].iter() {
+            let wins: CardSet = game.tricks().iter()
+                .filter(|trick| trick.winner == player)
+                .flat_map(|trick| vec![trick.lead_card, trick.reply_card])
+                .collect();
+            assert_eq!(game.get_player(player).wins, wins);
+        }
+
+        for (index, trick) in game.tricks().iter().enumerate() {
+            assert_eq!(index as u32 + 1, trick.number);
+        }
+    }
+}
+
+#[test]
+fn test_trick_number_in_built_position() {
+    let mut game = marriage_win_game(Suit::Acorns);
+    assert!(game.tricks().is_empty());
+
+    let leaves_king = Card::new(Suit::Leaves, Rank::King);
+    let leaves_unter = Card::new(Suit::Leaves, Rank::Unter);
+    assert!(game.play_card(leaves_king).is_ok());
+    assert!(game.play_card(leaves_unter).is_ok());
+
+    // Eight tricks are in the piles of the built position.
+    let trick = game.last_trick().unwrap();
+    assert_eq!(9, trick.number);
+    assert_eq!(1, game.tricks().len());
+}
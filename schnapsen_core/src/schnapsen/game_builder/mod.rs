@@ -0,0 +1,343 @@
+use cards::{Card, Rank, Suit};
+use schnapsen::card_set::CardSet;
+use schnapsen::generate_deck;
+use schnapsen::game::Game;
+use schnapsen::game_data::{Closing, PublicGameData};
+use schnapsen::player::{Player, PlayerId};
+
+#[derive(Copy, Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum BuildError {
+    IllegalCard(Card),
+    DuplicateCard(Card),
+    MissingCard(Card),
+    MissingTrump,
+    TrumpMismatch(Suit),
+    OddStockSize(usize),
+    OddNumberOfWins(PlayerId),
+    WrongHandSize(PlayerId, usize),
+    TwentyWithTrumpSuit(PlayerId),
+    DuplicateTwenty(PlayerId, Suit),
+    MarriageDeclaredByBoth(Suit),
+    // A card of a declared marriage is in the opponent's hand.
+    MarriageCardWithOpponent(PlayerId, Card),
+    // Both cards of a declared marriage are still in the declarer's hand,
+    // although one of them was led to declare it.
+    MarriageNotLed(PlayerId, Suit),
+    // The closer given by closed_by is not the one of the closing data.
+    CloserMismatch(PlayerId),
+    NotEnoughCardsInStockToClose
+}
+
+#[derive(Clone, Debug, Default)]
+struct PlayerSetup {
+    hand: Vec<Card>,
+    wins: Vec<Card>,
+    twenties: Vec<Suit>,
+    forty: bool
+}
+
+// Builds a game in an arbitrary position. Every card of the deck has to be
+// in exactly one place: in a hand, in the stock, in a trick pile, or on the
+// table as the first card of the current trick.
+#[derive(Clone, Debug)]
+pub struct GameBuilder {
+    player1: PlayerSetup,
+    player2: PlayerSetup,
+    stock: Vec<Card>,
+    trump_card: Option<Card>,
+    trump: Option<Suit>,
+    closed_by: Option<PlayerId>,
+    closing: Option<Closing>,
+    player_on_lead: PlayerId,
+    card_on_lead: Option<Card>
+}
+
+impl Default for GameBuilder {
+    fn default() -> Self {
+        GameBuilder::new()
+    }
+}
+
+impl GameBuilder {
+    pub fn new() -> GameBuilder {
+        GameBuilder {
+            player1: PlayerSetup::default(),
+            player2: PlayerSetup::default(),
+            stock: Vec::new(),
+            trump_card: None,
+            trump: None,
+            closed_by: None,
+            closing: None,
+            player_on_lead: PlayerId::Player1,
+            card_on_lead: None
+        }
+    }
+
+    pub fn hand(mut self, player: PlayerId, hand: Vec<Card>) -> GameBuilder {
+        self.setup_mut(player).hand = hand;
+        self
+    }
+
+    pub fn wins(mut self, player: PlayerId, wins: Vec<Card>) -> GameBuilder {
+        self.setup_mut(player).wins = wins;
+        self
+    }
+
+    pub fn twenties(mut self, player: PlayerId, twenties: Vec<Suit>)
+                    -> GameBuilder {
+        self.setup_mut(player).twenties = twenties;
+        self
+    }
+
+    pub fn forty(mut self, player: PlayerId) -> GameBuilder {
+        self.setup_mut(player).forty = true;
+        self
+    }
+
+    // The face-down cards of the stock without the trump card. The last card
+    // is dealt first.
+    pub fn stock(mut self, stock: Vec<Card>) -> GameBuilder {
+        self.stock = stock;
+        self
+    }
+
+    // The face-up card at the bottom of the stock.
+    pub fn trump_card(mut self, trump_card: Card) -> GameBuilder {
+        self.trump_card = Some(trump_card);
+        self
+    }
+
+    // Only needed if there is no trump card, i.e. the stock is empty.
+    pub fn trump(mut self, trump: Suit) -> GameBuilder {
+        self.trump = Some(trump);
+        self
+    }
+
+    // The deck is closed in the built position; the closing data is taken
+    // from the built position as well.
+    pub fn closed_by(mut self, player: PlayerId) -> GameBuilder {
+        self.closed_by = Some(player);
+        self
+    }
+
+    // The deck was closed earlier with the given closing data. Takes
+    // precedence over closed_by, which has to name the same closer.
+    pub fn closing(mut self, closing: Closing) -> GameBuilder {
+        self.closing = Some(closing);
+        self
+    }
+
+    pub fn player_on_lead(mut self, player: PlayerId) -> GameBuilder {
+        self.player_on_lead = player;
+        self
+    }
+
+    // The card already played by the player on lead in the current trick.
+    pub fn card_on_lead(mut self, card: Card) -> GameBuilder {
+        self.card_on_lead = Some(card);
+        self
+    }
+
+    pub fn build(self) -> Result<Game, BuildError> {
+        self.check_cards()?;
+        let trump = self.check_trump()?;
+        self.check_sizes()?;
+        self.check_marriages(trump)?;
+
+        let mut stock: Vec<Card> = self.trump_card.into_iter().collect();
+        stock.extend_from_slice(&self.stock);
+
+        let player1 = self.player1.to_player("Player1", trump);
+        let player2 = self.player2.to_player("Player2", trump);
+
+        if let (Some(closing), Some(closer)) = (self.closing, self.closed_by) {
+            if closing.closer != closer {
+                return Err(BuildError::CloserMismatch(closer));
+            }
+        }
+
+        let closing = match (self.closing, self.closed_by) {
+            (Some(closing), _) => Some(closing),
+            (None, Some(closer)) => {
+                let opponent = match closer {
+                    PlayerId::Player1 => &player2,
+                    PlayerId::Player2 => &player1
+                };
+
+                Some(Closing {closer, stock_size: stock.len() as u32,
+                              opponent_score: opponent.score(),
+                              opponent_has_trick: opponent.has_won_trick()})
+            },
+            (None, None) => None
+        };
+
+        if closing.is_some() && stock.len() <= 2 {
+            return Err(BuildError::NotEnoughCardsInStockToClose);
+        }
+
+        let public_data = PublicGameData {
+            trump, closed: closing.is_some(), closing, winner: None,
+            player_on_lead: self.player_on_lead,
+            first_card_in_trick: self.card_on_lead
+        };
+
+        Ok(Game::from_parts(stock, player1, player2, public_data))
+    }
+
+    fn setup(&self, player: PlayerId) -> &PlayerSetup {
+        match player {
+            PlayerId::Player1 => &self.player1,
+            PlayerId::Player2 => &self.player2
+        }
+    }
+
+    fn setup_mut(&mut self, player: PlayerId) -> &mut PlayerSetup {
+        match player {
+            PlayerId::Player1 => &mut self.player1,
+            PlayerId::Player2 => &mut self.player2
+        }
+    }
+
+    fn check_cards(&self) -> Result<(), BuildError> {
+        let mut cards = Vec::new();
+        cards.extend_from_slice(&self.player1.hand);
+        cards.extend_from_slice(&self.player2.hand);
+        cards.extend_from_slice(&self.player1.wins);
+        cards.extend_from_slice(&self.player2.wins);
+        cards.extend_from_slice(&self.stock);
+        cards.extend(self.trump_card);
+        cards.extend(self.card_on_lead);
+
+        let deck = generate_deck();
+
+        for (index, card) in cards.iter().enumerate() {
+            if !deck.contains(card) {
+                return Err(BuildError::IllegalCard(*card));
+            }
+
+            if cards[..index].contains(card) {
+                return Err(BuildError::DuplicateCard(*card));
+            }
+        }
+
+        match deck.iter().find(|card| !cards.contains(card)) {
+            Some(&card) => Err(BuildError::MissingCard(card)),
+            None => Ok(())
+        }
+    }
+
+    fn check_trump(&self) -> Result<Suit, BuildError> {
+        match (self.trump_card, self.trump) {
+            (Some(card), Some(trump)) if card.suit() != trump
+                => Err(BuildError::TrumpMismatch(trump)),
+            (Some(card), _) => Ok(card.suit()),
+            (None, Some(trump)) if self.stock.is_empty() => Ok(trump),
+            _ => Err(BuildError::MissingTrump)
+        }
+    }
+
+    fn check_marriages(&self, trump: Suit) -> Result<(), BuildError> {
+        let players = [PlayerId::Player1, PlayerId::Player2];
+
+        for &player_id in players.iter() {
+            let twenties = &self.setup(player_id).twenties;
+
+            for (index, &suit) in twenties.iter().enumerate() {
+                if suit == trump {
+                    return Err(BuildError::TwentyWithTrumpSuit(player_id));
+                }
+
+                if twenties[..index].contains(&suit) {
+                    return Err(BuildError::DuplicateTwenty(player_id, suit));
+                }
+            }
+        }
+
+        if self.player1.forty && self.player2.forty {
+            return Err(BuildError::MarriageDeclaredByBoth(trump));
+        }
+
+        for &suit in self.player1.twenties.iter() {
+            if self.player2.twenties.contains(&suit) {
+                return Err(BuildError::MarriageDeclaredByBoth(suit));
+            }
+        }
+
+        // The cards of a marriage may have been won by the opponent since it
+        // was declared, but they can never get into the opponent's hand.
+        for &player_id in players.iter() {
+            let setup = self.setup(player_id);
+            let opponent_hand = &self.setup(player_id.other()).hand;
+            let forty = if setup.forty { Some(trump) } else { None };
+
+            for suit in setup.twenties.iter().cloned().chain(forty) {
+                let cards = [Card::new(suit, Rank::Ober),
+                             Card::new(suit, Rank::King)];
+
+                if let Some(&card) = cards.iter()
+                    .find(|card| opponent_hand.contains(card)) {
+                    return Err(BuildError::MarriageCardWithOpponent(player_id,
+                                                                   card));
+                }
+
+                if cards.iter().all(|card| setup.hand.contains(card)) {
+                    return Err(BuildError::MarriageNotLed(player_id, suit));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn check_sizes(&self) -> Result<(), BuildError> {
+        let stock_size = self.stock.len()
+            + self.trump_card.map(|_| 1).unwrap_or(0);
+        if stock_size % 2 == 1 {
+            return Err(BuildError::OddStockSize(stock_size));
+        }
+
+        for &player_id in [PlayerId::Player1, PlayerId::Player2].iter() {
+            if self.setup(player_id).wins.len() % 2 == 1 {
+                return Err(BuildError::OddNumberOfWins(player_id));
+            }
+        }
+
+        let leader = self.player_on_lead;
+        let follower = leader.other();
+
+        // The cards the player on lead had at the start of the trick.
+        let leader_hand_size = self.setup(leader).hand.len()
+            + self.card_on_lead.map(|_| 1).unwrap_or(0);
+        let follower_hand_size = self.setup(follower).hand.len();
+
+        let in_stock_phase = stock_size > 0 && self.closed_by.is_none()
+            && self.closing.is_none();
+        let max_hand_size = 5;
+
+        if follower_hand_size > max_hand_size
+            || (in_stock_phase && follower_hand_size != max_hand_size) {
+            Err(BuildError::WrongHandSize(follower, follower_hand_size))
+        } else if leader_hand_size != follower_hand_size {
+            Err(BuildError::WrongHandSize(leader, leader_hand_size))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl PlayerSetup {
+    fn to_player(&self, name: &str, trump: Suit) -> Player {
+        let forty = if self.forty {
+            Some(trump)
+        } else {
+            None
+        };
+
+        Player {name: name.to_string(), hand: CardSet::from(&self.hand[..]),
+                wins: CardSet::from(&self.wins[..]),
+                twenties: self.twenties.clone(), forty, ..Default::default()}
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,278 @@
+use super::*;
+use cards::Rank;
+use schnapsen::ErrorKind;
+
+fn mid_game_builder() -> GameBuilder {
+    GameBuilder::new()
+        .hand(PlayerId::Player1,
+              vec![Card::new(Suit::Hearts, Rank::Ace),
+                   Card::new(Suit::Hearts, Rank::Ten),
+                   Card::new(Suit::Bells, Rank::Ober),
+                   Card::new(Suit::Bells, Rank::King),
+                   Card::new(Suit::Leaves, Rank::Unter)])
+        .hand(PlayerId::Player2,
+              vec![Card::new(Suit::Acorns, Rank::Ace),
+                   Card::new(Suit::Acorns, Rank::Ten),
+                   Card::new(Suit::Acorns, Rank::King),
+                   Card::new(Suit::Leaves, Rank::Ober),
+                   Card::new(Suit::Leaves, Rank::King)])
+        .wins(PlayerId::Player1,
+              vec![Card::new(Suit::Bells, Rank::Ace),
+                   Card::new(Suit::Bells, Rank::Unter),
+                   Card::new(Suit::Leaves, Rank::Ten),
+                   Card::new(Suit::Leaves, Rank::Ace)])
+        .wins(PlayerId::Player2,
+              vec![Card::new(Suit::Acorns, Rank::Unter),
+                   Card::new(Suit::Acorns, Rank::Ober)])
+        .trump_card(Card::new(Suit::Hearts, Rank::Unter))
+        .stock(vec![Card::new(Suit::Hearts, Rank::Ober),
+                    Card::new(Suit::Hearts, Rank::King),
+                    Card::new(Suit::Bells, Rank::Ten)])
+}
+
+#[test]
+fn test_build_mid_game() {
+    let game = mid_game_builder()
+        .twenties(PlayerId::Player2, vec![Suit::Acorns])
+        .player_on_lead(PlayerId::Player2)
+        .build()
+        .unwrap();
+
+    assert_eq!(Suit::Hearts, game.trump());
+    assert_eq!(Some(Card::new(Suit::Hearts, Rank::Unter)), game.trump_card());
+    assert_eq!(PlayerId::Player2, game.player_on_lead());
+    assert_eq!(PlayerId::Player2, game.player_on_turn());
+    assert!(!game.is_closed());
+    assert_eq!(34, game.get_player1().score());
+    assert_eq!(5 + 20, game.get_player2().score());
+}
+
+#[test]
+fn test_build_deals_from_end_of_stock() {
+    let mut game = mid_game_builder().build().unwrap();
+
+    assert!(game.play_card(Card::new(Suit::Hearts, Rank::Ace)).is_ok());
+    let dealt_cards = game.play_card(Card::new(Suit::Acorns, Rank::Ten));
+
+    let expected_cards = Some((Card::new(Suit::Bells, Rank::Ten),
+                               Card::new(Suit::Hearts, Rank::King)));
+    assert_eq!(Ok(expected_cards), dealt_cards);
+}
+
+#[test]
+fn test_build_card_on_lead() {
+    let game = mid_game_builder()
+        .hand(PlayerId::Player1,
+              vec![Card::new(Suit::Hearts, Rank::Ten),
+                   Card::new(Suit::Bells, Rank::Ober),
+                   Card::new(Suit::Bells, Rank::King),
+                   Card::new(Suit::Leaves, Rank::Unter)])
+        .card_on_lead(Card::new(Suit::Hearts, Rank::Ace))
+        .build()
+        .unwrap();
+
+    assert_eq!(PlayerId::Player2, game.player_on_turn());
+    assert_eq!(Err(ErrorKind::NoSuchCardInHand(
+        Card::new(Suit::Hearts, Rank::Ace))),
+               game.can_play_card(Card::new(Suit::Hearts, Rank::Ace)));
+}
+
+#[test]
+fn test_build_closed() {
+    let game = mid_game_builder()
+        .forty(PlayerId::Player1)
+        .closed_by(PlayerId::Player1)
+        .build()
+        .unwrap();
+
+    assert!(game.is_closed());
+    assert_eq!(None, game.trump_card());
+    assert_eq!(Some(Suit::Hearts), game.get_player1().forty);
+
+    let expected_closing = Closing {closer: PlayerId::Player1,
+                                    stock_size: 4,
+                                    opponent_score: 5,
+                                    opponent_has_trick: true};
+    assert_eq!(Some(expected_closing), game.closing());
+}
+
+#[test]
+fn test_build_missing_card() {
+    let result = mid_game_builder()
+        .stock(vec![Card::new(Suit::Hearts, Rank::Ober),
+                    Card::new(Suit::Hearts, Rank::King)])
+        .build();
+
+    assert_eq!(BuildError::MissingCard(Card::new(Suit::Bells, Rank::Ten)),
+               result.unwrap_err());
+}
+
+#[test]
+fn test_build_duplicate_card() {
+    let result = mid_game_builder()
+        .stock(vec![Card::new(Suit::Hearts, Rank::Ober),
+                    Card::new(Suit::Hearts, Rank::King),
+                    Card::new(Suit::Hearts, Rank::Ace)])
+        .build();
+
+    assert_eq!(BuildError::DuplicateCard(Card::new(Suit::Hearts, Rank::Ace)),
+               result.unwrap_err());
+}
+
+#[test]
+fn test_build_illegal_card() {
+    let result = mid_game_builder()
+        .card_on_lead(Card::new(Suit::Hearts, Rank::Seven))
+        .build();
+
+    assert_eq!(BuildError::IllegalCard(Card::new(Suit::Hearts, Rank::Seven)),
+               result.unwrap_err());
+}
+
+#[test]
+fn test_build_trump_mismatch() {
+    let result = mid_game_builder().trump(Suit::Bells).build();
+
+    assert_eq!(BuildError::TrumpMismatch(Suit::Bells), result.unwrap_err());
+}
+
+#[test]
+fn test_build_missing_trump() {
+    let deck = generate_deck();
+    let result = GameBuilder::new()
+        .hand(PlayerId::Player1, deck[..5].to_vec())
+        .hand(PlayerId::Player2, deck[5..10].to_vec())
+        .stock(deck[10..].to_vec())
+        .build();
+
+    assert_eq!(BuildError::MissingTrump, result.unwrap_err());
+}
+
+#[test]
+fn test_build_wrong_hand_size() {
+    let deck = generate_deck();
+    let result = GameBuilder::new()
+        .hand(PlayerId::Player1, deck[..4].to_vec())
+        .hand(PlayerId::Player2, deck[4..10].to_vec())
+        .trump_card(deck[10])
+        .stock(deck[11..].to_vec())
+        .build();
+
+    assert_eq!(BuildError::WrongHandSize(PlayerId::Player2, 6),
+               result.unwrap_err());
+}
+
+#[test]
+fn test_build_twenty_with_trump_suit() {
+    let result = mid_game_builder()
+        .twenties(PlayerId::Player1, vec![Suit::Hearts])
+        .build();
+
+    assert_eq!(BuildError::TwentyWithTrumpSuit(PlayerId::Player1),
+               result.unwrap_err());
+}
+
+#[test]
+fn test_build_duplicate_twenty() {
+    let result = mid_game_builder()
+        .twenties(PlayerId::Player2, vec![Suit::Acorns, Suit::Acorns])
+        .build();
+
+    assert_eq!(BuildError::DuplicateTwenty(PlayerId::Player2, Suit::Acorns),
+               result.unwrap_err());
+}
+
+#[test]
+fn test_build_marriage_declared_by_both() {
+    let result = mid_game_builder()
+        .forty(PlayerId::Player1)
+        .forty(PlayerId::Player2)
+        .build();
+
+    assert_eq!(BuildError::MarriageDeclaredByBoth(Suit::Hearts),
+               result.unwrap_err());
+
+    let result = mid_game_builder()
+        .twenties(PlayerId::Player1, vec![Suit::Bells])
+        .twenties(PlayerId::Player2, vec![Suit::Bells])
+        .build();
+
+    assert_eq!(BuildError::MarriageDeclaredByBoth(Suit::Bells),
+               result.unwrap_err());
+}
+
+#[test]
+fn test_build_marriage_card_with_opponent() {
+    let result = mid_game_builder()
+        .twenties(PlayerId::Player1, vec![Suit::Acorns])
+        .build();
+
+    assert_eq!(BuildError::MarriageCardWithOpponent(
+        PlayerId::Player1, Card::new(Suit::Acorns, Rank::King)),
+               result.unwrap_err());
+
+    // The Acorns Ober has been played and won since.
+    assert!(mid_game_builder()
+            .twenties(PlayerId::Player2, vec![Suit::Acorns])
+            .build()
+            .is_ok());
+}
+
+#[test]
+fn test_build_marriage_not_led() {
+    let result = mid_game_builder()
+        .twenties(PlayerId::Player1, vec![Suit::Bells])
+        .build();
+
+    assert_eq!(BuildError::MarriageNotLed(PlayerId::Player1, Suit::Bells),
+               result.unwrap_err());
+
+    // The King was led to declare the marriage and is still on the table.
+    let bells_king = Card::new(Suit::Bells, Rank::King);
+    let mut hand = mid_game_builder().player1.hand;
+    hand.retain(|&card| card != bells_king);
+
+    assert!(mid_game_builder()
+            .hand(PlayerId::Player1, hand)
+            .card_on_lead(bells_king)
+            .twenties(PlayerId::Player1, vec![Suit::Bells])
+            .build()
+            .is_ok());
+}
+
+#[test]
+fn test_build_closer_mismatch() {
+    let closing = Closing {closer: PlayerId::Player2, stock_size: 6,
+                           opponent_score: 0, opponent_has_trick: false};
+
+    let result = mid_game_builder()
+        .closing(closing)
+        .closed_by(PlayerId::Player1)
+        .build();
+    assert_eq!(BuildError::CloserMismatch(PlayerId::Player1),
+               result.unwrap_err());
+
+    let game = mid_game_builder()
+        .closing(closing)
+        .closed_by(PlayerId::Player2)
+        .build()
+        .unwrap();
+    assert_eq!(Some(closing), game.closing());
+}
+
+#[test]
+fn test_build_endgame_without_stock() {
+    let deck = generate_deck();
+    let game = GameBuilder::new()
+        .hand(PlayerId::Player1, deck[..2].to_vec())
+        .hand(PlayerId::Player2, deck[2..4].to_vec())
+        .wins(PlayerId::Player1, deck[4..12].to_vec())
+        .wins(PlayerId::Player2, deck[12..].to_vec())
+        .trump(Suit::Leaves)
+        .build()
+        .unwrap();
+
+    assert_eq!(Suit::Leaves, game.trump());
+    assert_eq!(None, game.trump_card());
+    assert_eq!(Err(ErrorKind::NotEnoughCardsInStock), game.can_close());
+}
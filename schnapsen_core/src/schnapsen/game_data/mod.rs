@@ -1,10 +1,30 @@
 use cards::{Card, Suit};
 use schnapsen::player::PlayerId;
 
-#[derive(Debug, Deserialize, Serialize)]
+// Who closed the deck and the state of the game at that moment, as needed
+// for scoring the deal.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Closing {
+    pub closer: PlayerId,
+    pub stock_size: u32,
+    pub opponent_score: u32,
+    pub opponent_has_trick: bool
+}
+
+// The deck a deal was dealt from, see Game::from_deck, and who led first.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct DealOrigin {
+    pub deck: Vec<Card>,
+    pub player_on_lead: PlayerId,
+    // Set if the deck was generated from a seed.
+    pub seed: Option<u64>
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct PublicGameData {
     pub trump: Suit,
     pub closed: bool,
+    pub closing: Option<Closing>,
     pub winner: Option<PlayerId>,
     pub player_on_lead: PlayerId,
     pub first_card_in_trick: Option<Card>
@@ -13,7 +33,7 @@ pub struct PublicGameData {
 impl Default for PublicGameData {
     fn default() -> PublicGameData {
         PublicGameData {
-            trump: Suit::Hearts, closed: false,
+            trump: Suit::Hearts, closed: false, closing: None,
             winner: None, player_on_lead: PlayerId::Player1,
             first_card_in_trick: None
         }
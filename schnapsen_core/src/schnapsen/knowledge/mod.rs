@@ -0,0 +1,158 @@
+use rand::Rng;
+
+use cards::Card;
+use schnapsen::generate_deck;
+use schnapsen::game::Game;
+use schnapsen::game_builder::GameBuilder;
+use schnapsen::player::PlayerId;
+use schnapsen::player_view::PlayerView;
+
+// Where a card is from the point of view of a player.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum CardLocation {
+    OwnHand,
+    // In one of the trick piles or on the table.
+    Played,
+    // At the bottom of the stock, face up or turned down by closing.
+    TrumpCard,
+    // Revealed by a marriage or by exchanging the trump.
+    OpponentHand,
+    // Either in the opponent's hand or in the stock.
+    Unknown
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct CardKnowledge {
+    player_id: PlayerId,
+    locations: Vec<(Card, CardLocation)>,
+    opponent_hand_size: u32,
+    stock_size: u32
+}
+
+impl CardKnowledge {
+    pub fn from_view(view: &PlayerView) -> CardKnowledge {
+        let trump_card = view.trump_card.or(view.closed_trump_card);
+
+        let locations = generate_deck().into_iter().map(|card| {
+            let location = if view.hand.contains(&card) {
+                CardLocation::OwnHand
+            } else if view.own_wins.contains(&card)
+                || view.opponent_wins.contains(&card)
+                || view.card_on_lead == Some(card) {
+                CardLocation::Played
+            } else if trump_card == Some(card) {
+                CardLocation::TrumpCard
+            } else if view.opponent_revealed.contains(&card) {
+                CardLocation::OpponentHand
+            } else {
+                CardLocation::Unknown
+            };
+
+            (card, location)
+        }).collect();
+
+        CardKnowledge {player_id: view.player_id, locations,
+                       opponent_hand_size: view.opponent_hand_size,
+                       stock_size: view.stock_size}
+    }
+
+    pub fn player_id(&self) -> PlayerId {
+        self.player_id
+    }
+
+    pub fn location(&self, card: Card) -> Option<CardLocation> {
+        self.locations.iter()
+            .find(|&&(other_card, _)| other_card == card)
+            .map(|&(_, location)| location)
+    }
+
+    pub fn cards(&self, location: CardLocation) -> Vec<Card> {
+        self.locations.iter()
+            .filter(|&&(_, other_location)| other_location == location)
+            .map(|&(card, _)| card)
+            .collect()
+    }
+
+    pub fn played(&self) -> Vec<Card> {
+        self.cards(CardLocation::Played)
+    }
+
+    pub fn revealed(&self) -> Vec<Card> {
+        self.cards(CardLocation::OpponentHand)
+    }
+
+    pub fn trump_card(&self) -> Option<Card> {
+        self.cards(CardLocation::TrumpCard).first().cloned()
+    }
+
+    pub fn unknown(&self) -> Vec<Card> {
+        self.cards(CardLocation::Unknown)
+    }
+
+    // How many of the unknown cards are in the opponent's hand.
+    pub fn unknown_in_opponent_hand(&self) -> u32 {
+        self.opponent_hand_size - self.revealed().len() as u32
+    }
+
+    // How many of the unknown cards are in the stock.
+    pub fn unknown_in_stock(&self) -> u32 {
+        let trump_card_in_stock = self.trump_card().map(|_| 1).unwrap_or(0);
+        self.stock_size - trump_card_in_stock
+    }
+}
+
+// A complete game that is consistent with everything the player knows. The
+// unknown cards are distributed randomly between the opponent's hand and the
+// stock.
+pub fn sample_game<R: Rng>(view: &PlayerView, rng: &mut R) -> Game {
+    let knowledge = CardKnowledge::from_view(view);
+
+    let mut unknown = knowledge.unknown();
+    rng.shuffle(&mut unknown);
+
+    let mut opponent_hand = knowledge.revealed();
+    let unknown_in_opponent_hand
+        = knowledge.unknown_in_opponent_hand() as usize;
+    let stock = unknown.split_off(unknown_in_opponent_hand);
+    opponent_hand.extend(unknown);
+
+    let player = view.player_id;
+    let opponent = view.opponent_id();
+
+    let mut builder = GameBuilder::new()
+        .hand(player, view.hand.clone())
+        .hand(opponent, opponent_hand)
+        .wins(player, view.own_wins.clone())
+        .wins(opponent, view.opponent_wins.clone())
+        .twenties(player, view.own_twenties.clone())
+        .twenties(opponent, view.opponent_twenties.clone())
+        .stock(stock)
+        .trump(view.trump)
+        .player_on_lead(view.player_on_lead);
+
+    if let Some(trump_card) = knowledge.trump_card() {
+        builder = builder.trump_card(trump_card);
+    }
+
+    if view.own_forty {
+        builder = builder.forty(player);
+    }
+
+    if view.opponent_forty {
+        builder = builder.forty(opponent);
+    }
+
+    if let Some(closing) = view.closing {
+        builder = builder.closing(closing);
+    }
+
+    if let Some(card) = view.card_on_lead {
+        builder = builder.card_on_lead(card);
+    }
+
+    builder.build()
+        .expect("the player view should describe a valid position")
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,190 @@
+use super::*;
+use cards::{Suit, Rank};
+use schnapsen::game::Game;
+use schnapsen::moves::Move;
+
+fn assert_consistent(knowledge: &CardKnowledge) {
+    assert_eq!(knowledge.unknown().len() as u32,
+               knowledge.unknown_in_opponent_hand()
+               + knowledge.unknown_in_stock());
+}
+
+#[test]
+fn test_knowledge_new_game() {
+    let game = Game::default();
+    let knowledge = CardKnowledge::from_view(&game.view_for(PlayerId::Player1));
+
+    assert_eq!(PlayerId::Player1, knowledge.player_id());
+    assert_eq!(game.get_player1().hand.to_vec(),
+               knowledge.cards(CardLocation::OwnHand));
+    assert_eq!(game.trump_card(), knowledge.trump_card());
+    assert!(knowledge.played().is_empty());
+    assert!(knowledge.revealed().is_empty());
+    assert_eq!(14, knowledge.unknown().len());
+    assert_eq!(5, knowledge.unknown_in_opponent_hand());
+    assert_eq!(9, knowledge.unknown_in_stock());
+    assert_consistent(&knowledge);
+}
+
+#[test]
+fn test_knowledge_played_and_revealed() {
+    let mut game = Game::default();
+
+    let king = Card::new(Suit::Leaves, Rank::King);
+    assert!(game.play_card_twenty(king).is_ok());
+
+    let knowledge = CardKnowledge::from_view(&game.view_for(PlayerId::Player2));
+
+    assert_eq!(Some(CardLocation::Played), knowledge.location(king));
+    assert_eq!(Some(CardLocation::OpponentHand),
+               knowledge.location(Card::new(Suit::Leaves, Rank::Ober)));
+    assert_eq!(3, knowledge.unknown_in_opponent_hand());
+    assert_eq!(9, knowledge.unknown_in_stock());
+    assert_eq!(12, knowledge.unknown().len());
+    assert_consistent(&knowledge);
+
+    let card = Card::new(Suit::Acorns, Rank::Ace);
+    assert!(game.play_card(card).is_ok());
+
+    let knowledge = CardKnowledge::from_view(&game.view_for(PlayerId::Player1));
+    assert_eq!(Some(CardLocation::Played), knowledge.location(card));
+    assert_eq!(2, knowledge.played().len());
+    assert_eq!(5, knowledge.cards(CardLocation::OwnHand).len());
+    assert_consistent(&knowledge);
+}
+
+#[test]
+fn test_knowledge_closed_trump_card() {
+    let mut game = Game::default();
+    let trump_card = game.trump_card().unwrap();
+
+    assert!(game.close().is_ok());
+
+    let knowledge = CardKnowledge::from_view(&game.view_for(PlayerId::Player2));
+    assert_eq!(Some(CardLocation::TrumpCard), knowledge.location(trump_card));
+    assert_consistent(&knowledge);
+}
+
+#[test]
+fn test_knowledge_illegal_card() {
+    let game = Game::default();
+    let knowledge = CardKnowledge::from_view(&game.view_for(PlayerId::Player1));
+
+    assert_eq!(None, knowledge.location(Card::new(Suit::Hearts, Rank::Nine)));
+}
+
+#[test]
+fn test_knowledge_consistent_during_game() {
+    let mut game = Game::new_seeded(21);
+
+    while !game.is_game_over() {
+        for &player_id in [PlayerId::Player1, PlayerId::Player2].iter() {
+            let knowledge = CardKnowledge::from_view(&game.view_for(player_id));
+            assert_consistent(&knowledge);
+
+            let opponent_hand = &game.get_player(player_id.other()).hand;
+            for card in knowledge.revealed() {
+                assert!(opponent_hand.contains(card));
+            }
+        }
+
+        let mv = game.legal_moves()[0];
+        assert!(game.apply(mv).is_ok());
+    }
+}
+
+fn assert_sample_consistent(game: &Game, player_id: PlayerId) {
+    let view = game.view_for(player_id);
+    let mut rng = ::schnapsen::seeded_rng(3);
+
+    for _ in 0..10 {
+        let sample = sample_game(&view, &mut rng);
+        let sample_view = sample.view_for(player_id);
+
+        assert_eq!(view.hand, sample_view.hand);
+        assert_eq!(view.trump, sample_view.trump);
+        assert_eq!(view.trump_card.is_some(), sample_view.trump_card.is_some());
+        assert_eq!(view.stock_size, sample_view.stock_size);
+        assert_eq!(view.closing, sample_view.closing);
+        assert_eq!(view.player_on_lead, sample_view.player_on_lead);
+        assert_eq!(view.card_on_lead, sample_view.card_on_lead);
+        assert_eq!(view.own_wins, sample_view.own_wins);
+        assert_eq!(view.opponent_wins, sample_view.opponent_wins);
+        assert_eq!(view.opponent_hand_size, sample_view.opponent_hand_size);
+        if game.player_on_turn() == player_id {
+            assert_eq!(game.legal_moves(), sample.legal_moves());
+        }
+
+        let opponent_hand = &sample.get_player(player_id.other()).hand;
+        for card in &view.opponent_revealed {
+            assert!(opponent_hand.contains(*card));
+        }
+    }
+}
+
+#[test]
+fn test_sample_game_new_game() {
+    let game = Game::default();
+
+    assert_sample_consistent(&game, PlayerId::Player1);
+    assert_sample_consistent(&game, PlayerId::Player2);
+}
+
+#[test]
+fn test_sample_game_keeps_revealed_and_closing() {
+    let mut game = Game::default();
+
+    assert!(game.play_card_twenty(Card::new(Suit::Leaves, Rank::King)).is_ok());
+    assert_sample_consistent(&game, PlayerId::Player2);
+
+    assert!(game.play_card(Card::new(Suit::Acorns, Rank::Ace)).is_ok());
+    assert_sample_consistent(&game, PlayerId::Player1);
+
+    assert!(game.close().is_ok());
+    assert_sample_consistent(&game, PlayerId::Player1);
+    assert_sample_consistent(&game, PlayerId::Player2);
+}
+
+#[test]
+fn test_sample_game_varies_hidden_cards() {
+    let game = Game::default();
+    let view = game.view_for(PlayerId::Player1);
+    let mut rng = ::schnapsen::seeded_rng(5);
+
+    let first = sample_game(&view, &mut rng);
+    let differs = (0..10).any(|_| {
+        sample_game(&view, &mut rng).get_player2().hand
+            != first.get_player2().hand
+    });
+
+    assert!(differs);
+}
+
+#[test]
+fn test_knowledge_dealt_trump_card() {
+    let mut game = Game::new_seeded(8);
+    let trump_card = game.trump_card().unwrap();
+
+    while game.stock_size() > 0 {
+        let mv = game.legal_moves().into_iter()
+            .find(|&mv| mv != Move::Close && mv != Move::ExchangeTrump)
+            .unwrap();
+        assert!(game.apply(mv).is_ok());
+    }
+
+    let holder = if game.get_player1().hand.contains(trump_card) {
+        PlayerId::Player1
+    } else {
+        PlayerId::Player2
+    };
+    assert!(game.get_player(holder).hand.contains(trump_card));
+
+    let view = game.view_for(holder.other());
+    assert!(view.opponent_revealed.contains(&trump_card));
+
+    let knowledge = CardKnowledge::from_view(&view);
+    assert_eq!(Some(CardLocation::OpponentHand),
+               knowledge.location(trump_card));
+    assert_consistent(&knowledge);
+    assert_sample_consistent(&game, holder.other());
+}
@@ -1,18 +1,58 @@
+mod agent;
+mod arena;
+mod bots;
+mod bummerl;
+mod card_set;
+mod deal_result;
+mod event;
 mod game;
+mod game_builder;
 mod game_adapter;
 mod game_data;
+mod knowledge;
+mod moves;
 mod player;
 mod player_game;
+mod player_view;
+mod record;
+mod replay;
+mod runner;
+mod snapshot;
+mod solver;
+mod trick;
+mod undo;
+mod zobrist;
+
+use rand::{Rng, SeedableRng};
+use rand::isaac::Isaac64Rng;
 
 use cards::{Card, Suit, Rank};
 
 use std::vec::Vec;
 
+pub use self::agent::{Agent, RandomAgent};
+pub use self::arena::{AgentFactory, Arena, ArenaError, ArenaMode, ArenaReport,
+                      Pairing, Standing};
+pub use self::bots::{IsmctsBot, IsmctsConfig, PimcBot, PimcConfig};
+pub use self::bummerl::Match;
+pub use self::card_set::CardSet;
+pub use self::deal_result::{DealEndReason, DealResult};
+pub use self::event::GameEvent;
 pub use self::game::Game;
+pub use self::game_builder::{BuildError, GameBuilder};
 pub use self::game_adapter::GameAdapter;
-pub use self::player::Player;
+pub use self::moves::{Move, ParseMoveError};
+pub use self::player::{Player, PlayerId};
 pub use self::player_game::PlayerGame;
-pub use self::game_data::PublicGameData;
+pub use self::player_view::PlayerView;
+pub use self::record::{GameRecord, RecordError};
+pub use self::replay::Replay;
+pub use self::runner::{run_deal, DealRecord, IllegalMove};
+pub use self::game_data::{Closing, DealOrigin, PublicGameData};
+pub use self::knowledge::{sample_game, CardKnowledge, CardLocation};
+pub use self::snapshot::{SnapshotError, SNAPSHOT_VERSION};
+pub use self::solver::{evaluate, solve, Solution};
+pub use self::trick::Trick;
 
 #[derive(Copy, Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub enum ErrorKind {
@@ -30,9 +70,26 @@ pub enum ErrorKind {
     MustUseAnotherSuit(Suit),
     MustTake(Card),
     MustUseTrump,
-    ScoreTooLow(u32)
+    ScoreTooLow(u32),
+    MarriageWithoutTrick,
+    DealNotOver,
+    MatchOver
+}
+
+#[derive(Copy, Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum DeckError {
+    WrongSize(usize),
+    IllegalCard(Card),
+    DuplicateCard(Card)
 }
 
+pub const DECK_SIZE: usize = 20;
+
+pub const SUITS: [Suit; 4] = [Suit::Hearts, Suit::Bells,
+                              Suit::Acorns, Suit::Leaves];
+pub const RANKS: [Rank; 5] = [Rank::Unter, Rank::Ober, Rank::King,
+                              Rank::Ten, Rank::Ace];
+
 pub fn value(card: Card) -> u32 {
     match card.rank() {
         Rank::Unter => 2,
@@ -46,10 +103,6 @@ pub fn value(card: Card) -> u32 {
 
 pub fn generate_deck() -> Vec<Card> {
     let mut vec = Vec::<Card>::new();
-    const SUITS: [Suit; 4] = [Suit::Hearts, Suit::Bells,
-                              Suit::Acorns, Suit::Leaves];
-    const RANKS: [Rank; 5] = [Rank::Unter, Rank::Ober, Rank::King,
-                              Rank::Ten, Rank::Ace];
 
     for suit in SUITS.iter() {
         for rank in RANKS.iter() {
@@ -60,6 +113,43 @@ pub fn generate_deck() -> Vec<Card> {
     vec
 }
 
+// Checks that the deck contains every card of the Schnapsen deck exactly once.
+pub fn validate_deck(deck: &[Card]) -> Result<(), DeckError> {
+    let legal_cards = generate_deck();
+
+    for (index, card) in deck.iter().enumerate() {
+        if !legal_cards.contains(card) {
+            return Err(DeckError::IllegalCard(*card));
+        }
+
+        if deck[..index].contains(card) {
+            return Err(DeckError::DuplicateCard(*card));
+        }
+    }
+
+    if deck.len() == DECK_SIZE {
+        Ok(())
+    } else {
+        Err(DeckError::WrongSize(deck.len()))
+    }
+}
+
+pub fn generate_shuffled_deck<R: Rng>(rng: &mut R) -> Vec<Card> {
+    let mut deck = generate_deck();
+    rng.shuffle(&mut deck);
+    deck
+}
+
+// The same seed always results in the same deck, so a deal can be replayed
+// with Game::from_deck.
+pub fn generate_seeded_deck(seed: u64) -> Vec<Card> {
+    generate_shuffled_deck(&mut seeded_rng(seed))
+}
+
+fn seeded_rng(seed: u64) -> Isaac64Rng {
+    Isaac64Rng::from_seed(&[seed])
+}
+
 pub fn first_beats_second(first_card: Card,
                           second_card: Card,
                           trump: Suit)
@@ -0,0 +1,102 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use cards::{Card, ParseCardError, Suit};
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum Move {
+    Close,
+    ExchangeTrump,
+    PlayTwenty(Card),
+    DeclareTwentyWin(Suit),
+    PlayForty(Card),
+    DeclareFortyWin,
+    DeclareWin,
+    PlayCard(Card)
+}
+
+impl Move {
+    // The card that is put on the table by this move, if any.
+    pub fn card(&self) -> Option<Card> {
+        match *self {
+            Move::PlayTwenty(card)
+                | Move::PlayForty(card)
+                | Move::PlayCard(card) => Some(card),
+            _ => None
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseMoveError {
+    UnknownMove(String),
+    InvalidCard(ParseCardError)
+}
+
+impl fmt::Display for ParseMoveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ParseMoveError::UnknownMove(ref mv)
+                => write!(f, "unknown move \"{}\"", mv),
+            ParseMoveError::InvalidCard(ref error) => write!(f, "{}", error)
+        }
+    }
+}
+
+impl Error for ParseMoveError {}
+
+impl From<ParseCardError> for ParseMoveError {
+    fn from(error: ParseCardError) -> ParseMoveError {
+        ParseMoveError::InvalidCard(error)
+    }
+}
+
+// Cards are written in the compact card notation, e.g. "HA". Marriages are
+// prefixed with their value ("20LK", "40HO"), declarations start with "win"
+// ("win", "win20L", "win40"), and the rest are "close" and "exchange".
+impl fmt::Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Move::Close => write!(f, "close"),
+            Move::ExchangeTrump => write!(f, "exchange"),
+            Move::PlayTwenty(card) => write!(f, "20{}", card),
+            Move::DeclareTwentyWin(suit) => write!(f, "win20{}", suit),
+            Move::PlayForty(card) => write!(f, "40{}", card),
+            Move::DeclareFortyWin => write!(f, "win40"),
+            Move::DeclareWin => write!(f, "win"),
+            Move::PlayCard(card) => write!(f, "{}", card)
+        }
+    }
+}
+
+impl FromStr for Move {
+    type Err = ParseMoveError;
+
+    fn from_str(s: &str) -> Result<Move, ParseMoveError> {
+        let s = s.trim();
+        let lower = s.to_lowercase();
+
+        let mv = match lower.as_str() {
+            "close" => Move::Close,
+            "exchange" => Move::ExchangeTrump,
+            "win" => Move::DeclareWin,
+            "win40" => Move::DeclareFortyWin,
+            _ if lower.starts_with("win20") => {
+                let suit = s["win20".len()..].parse::<Suit>()
+                    .map_err(|_| ParseMoveError::UnknownMove(s.to_string()))?;
+                Move::DeclareTwentyWin(suit)
+            },
+            _ if lower.starts_with("20") => Move::PlayTwenty(s[2..].parse()?),
+            _ if lower.starts_with("40") => Move::PlayForty(s[2..].parse()?),
+            _ if lower.starts_with("win") || lower.is_empty()
+                => return Err(ParseMoveError::UnknownMove(s.to_string())),
+            _ => Move::PlayCard(s.parse()?)
+        };
+
+        Ok(mv)
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,32 @@
+use super::*;
+use cards::Rank;
+
+#[test]
+fn test_move_notation_round_trip() {
+    let card = Card::new(Suit::Leaves, Rank::King);
+    let moves = [Move::Close, Move::ExchangeTrump, Move::PlayTwenty(card),
+                 Move::DeclareTwentyWin(Suit::Bells), Move::PlayForty(card),
+                 Move::DeclareFortyWin, Move::DeclareWin,
+                 Move::PlayCard(card)];
+    let notation = ["close", "exchange", "20LK", "win20B", "40LK", "win40",
+                    "win", "LK"];
+
+    for (mv, text) in moves.iter().zip(notation.iter()) {
+        assert_eq!(*text, mv.to_string());
+        assert_eq!(Ok(*mv), text.parse());
+        assert_eq!(Ok(*mv), text.to_uppercase().parse());
+    }
+}
+
+#[test]
+fn test_move_notation_errors() {
+    assert_eq!(Err(ParseMoveError::UnknownMove("winner".to_string())),
+               "winner".parse::<Move>());
+    assert_eq!(Err(ParseMoveError::UnknownMove("win20X".to_string())),
+               "win20X".parse::<Move>());
+    assert_eq!(Err(ParseMoveError::UnknownMove("".to_string())),
+               "".parse::<Move>());
+    assert_eq!(Err(ParseMoveError::InvalidCard(
+        ParseCardError::UnknownRank("X".to_string()))),
+               "20HX".parse::<Move>());
+}
@@ -1,4 +1,5 @@
 use cards::{Card, Suit};
+use schnapsen::card_set::CardSet;
 
 use std::vec::Vec;
 
@@ -17,13 +18,17 @@ impl PlayerId {
     }
 }
 
-#[derive(Default, Debug)]
+#[derive(Clone, Default, Debug, Deserialize, Serialize)]
 pub struct Player {
     pub(super) name: String,
-    pub(super) hand: Vec<Card>,
-    pub(super) wins: Vec<Card>,
+    pub(super) hand: CardSet,
+    pub(super) wins: CardSet,
     pub(super) twenties: Vec<Suit>,
-    pub(super) forty: Option<Suit>
+    pub(super) forty: Option<Suit>,
+
+    // Cards that the opponent has seen going into this player's hand.
+    #[serde(default)]
+    pub(super) revealed: Vec<Card>
 }
 
 impl Player {
@@ -31,17 +36,46 @@ impl Player {
         Player {name, ..Default::default()}
     }
 
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
     pub fn score(&self) -> u32 {
-        let tricks = sum_card_slice(&self.wins);
+        let tricks = self.wins.points();
+        let marriages = self.marriage_points()
+            - self.pending_marriage_points();
+
+        tricks + marriages
+    }
+
+    // Whether the players have the same cards and marriages, regardless of
+    // their names and what they have revealed.
+    pub(super) fn same_position(&self, other: &Player) -> bool {
+        self.hand == other.hand && self.wins == other.wins
+            && self.forty == other.forty
+            && self.twenties.len() == other.twenties.len()
+            && self.twenties.iter().all(|suit| other.twenties.contains(suit))
+    }
+
+    pub fn has_won_trick(&self) -> bool {
+        !self.wins.is_empty()
+    }
+
+    pub fn marriage_points(&self) -> u32 {
         let twenties = self.twenties.len() as u32 * 20;
         let forty = self.forty.map(|_| 40).unwrap_or(0);
 
-        tricks + twenties + forty
+        twenties + forty
     }
-}
 
-fn sum_card_slice(slice: &[Card]) -> u32 {
-    slice.iter().map(|&card| super::value(card)).sum()
+    // Marriage points only count once the player has won a trick.
+    pub fn pending_marriage_points(&self) -> u32 {
+        if self.has_won_trick() {
+            0
+        } else {
+            self.marriage_points()
+        }
+    }
 }
 
 #[cfg(test)]
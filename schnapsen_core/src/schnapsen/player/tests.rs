@@ -8,10 +8,37 @@ fn test_player_score() {
                     Card {suit: Suit::Bells, rank: Rank::King},
                     Card {suit: Suit::Bells, rank: Rank::Ober}];
 
-    let player = Player {name: "Player".to_string(), wins,
+    let player = Player {name: "Player".to_string(), wins: wins.into(),
                          twenties: vec![Suit::Bells, Suit::Hearts],
                          forty: Some(Suit::Leaves),
                          ..Default::default()};
 
     assert_eq!(28 + 2 * 20 + 40, player.score());
 }
+
+#[test]
+fn test_player_score_marriages_pending_without_trick() {
+    let player = Player {name: "Player".to_string(),
+                         twenties: vec![Suit::Bells],
+                         forty: Some(Suit::Leaves),
+                         ..Default::default()};
+
+    assert!(!player.has_won_trick());
+    assert_eq!(60, player.marriage_points());
+    assert_eq!(60, player.pending_marriage_points());
+    assert_eq!(0, player.score());
+}
+
+#[test]
+fn test_player_score_marriages_count_after_trick() {
+    let wins = vec![Card {suit: Suit::Leaves, rank: Rank::Unter},
+                    Card {suit: Suit::Bells, rank: Rank::Ober}];
+
+    let player = Player {name: "Player".to_string(), wins: wins.into(),
+                         twenties: vec![Suit::Bells],
+                         ..Default::default()};
+
+    assert!(player.has_won_trick());
+    assert_eq!(0, player.pending_marriage_points());
+    assert_eq!(5 + 20, player.score());
+}
@@ -3,6 +3,7 @@ use std::borrow::{Borrow, BorrowMut};
 use cards::{Card, Suit, Rank};
 
 use schnapsen::ErrorKind;
+use schnapsen::card_set::CardSet;
 use schnapsen::{first_beats_second, value};
 use schnapsen::game_data::PublicGameData;
 use schnapsen::player::{PlayerId, Player};
@@ -57,7 +58,7 @@ impl<P, D> PlayerGame<P, D>
         } else {
             let player = self.player.borrow();
             let trump_unter = Card::new(borrowed_data.trump, Rank::Unter);
-            if player.hand.contains(&trump_unter) {
+            if player.hand.contains(trump_unter) {
                 Ok(())
             } else {
                 Err(ErrorKind::NoSuchCardInHand(trump_unter))
@@ -75,15 +76,7 @@ impl<P, D> PlayerGame<P, D>
             return Err(ErrorKind::GameOver);
         }
         
-        let player = self.player.borrow();
-
-        let player_score = player.score();
-        
-        if player_score < 66 {
-            Err(ErrorKind::ScoreTooLow(player_score))
-        } else {
-            Ok(())
-        }
+        self.has_score(66)
     }
 
     pub fn can_play_card_twenty(&self, card: Card) -> Result<(), ErrorKind> {
@@ -110,9 +103,9 @@ impl<P, D> PlayerGame<P, D>
         let suit = card.suit();
         let other_card_in_twenty = Card::new(suit, other_rank_in_twenty);
         
-        if !player.hand.contains(&card) {
+        if !player.hand.contains(card) {
             Err(ErrorKind::NoSuchCardInHand(card))
-        } else if !player.hand.contains(&other_card_in_twenty) {
+        } else if !player.hand.contains(other_card_in_twenty) {
             Err(ErrorKind::NoSuchCardInHand(other_card_in_twenty))
         } else if player.twenties.contains(&suit) {
             Err(ErrorKind::AlreadyCalledThisTwenty(suit))
@@ -125,14 +118,7 @@ impl<P, D> PlayerGame<P, D>
 
     pub fn can_declare_twenty_win(&self, suit: Suit) -> Result<(), ErrorKind> {
         self.can_play_card_twenty(Card::new(suit, Rank::King))?;
-
-        let score = self.player.borrow().score();
-
-        if score >= 46 {
-            Ok(())
-        } else {
-            Err(ErrorKind::ScoreTooLow(score))
-        }
+        self.has_score(46)
     }
 
     pub fn can_play_card_forty(&self, card: Card) -> Result<(), ErrorKind> {
@@ -154,9 +140,9 @@ impl<P, D> PlayerGame<P, D>
         let ober = Card::new(borrowed_data.trump, Rank::Ober);
         let king = Card::new(borrowed_data.trump, Rank::King);
         
-        if !player.hand.contains(&ober) {
+        if !player.hand.contains(ober) {
             Err(ErrorKind::NoSuchCardInHand(ober))
-        } else if !player.hand.contains(&king) {
+        } else if !player.hand.contains(king) {
             Err(ErrorKind::NoSuchCardInHand(king))
         } else if player.forty.is_some() {
             Err(ErrorKind::AlreadyCalledForty)
@@ -168,14 +154,7 @@ impl<P, D> PlayerGame<P, D>
     pub fn can_declare_forty_win(&self) -> Result<(), ErrorKind> {
         let trump = self.public_data.borrow().trump;
         self.can_play_card_forty(Card::new(trump, Rank::King))?;
-
-        let score = self.player.borrow().score();
-
-        if score >= 26 {
-            Ok(())
-        } else {
-            Err(ErrorKind::ScoreTooLow(score))
-        }
+        self.has_score(26)
     }
 
     pub fn can_play_card(&self, card: Card) -> Result<(), ErrorKind> {
@@ -189,7 +168,7 @@ impl<P, D> PlayerGame<P, D>
         
         let player = self.player.borrow();
         
-        if !player.hand.contains(&card) {
+        if !player.hand.contains(card) {
             return Err(ErrorKind::NoSuchCardInHand(card));
         }
 
@@ -216,12 +195,25 @@ impl<P, D> PlayerGame<P, D>
         let player = self.player.borrow();
         
         let legal_second_card = legal_second_card_in_endgame(
-            first_card, &player.hand,
+            first_card, player.hand,
             card, borrowed_data.trump);
         
         legal_second_card
     }
     
+    fn has_score(&self, required_score: u32) -> Result<(), ErrorKind> {
+        let player = self.player.borrow();
+        let score = player.score();
+
+        if score >= required_score {
+            Ok(())
+        } else if score + player.pending_marriage_points() >= required_score {
+            Err(ErrorKind::MarriageWithoutTrick)
+        } else {
+            Err(ErrorKind::ScoreTooLow(score))
+        }
+    }
+    
     fn on_lead(&self) -> Result<(), ErrorKind> {
         if self.player_on_lead() == self.player_id {
             Ok(())
@@ -264,10 +256,8 @@ impl<P, D> PlayerGame<P, D>
             {
                 let player = self.player.borrow_mut();
                 
-                let index = player.hand.iter()
-                    .position(|&card| card == Card::new(trump, Rank::Unter))
-                    .unwrap();
-                player.hand[index] = trump_card;
+                player.hand.remove(Card::new(trump, Rank::Unter));
+                player.hand.insert(trump_card);
             }
         }
 
@@ -356,7 +346,8 @@ impl<P, D> PlayerGame<P, D>
 
                 if self.player_id == winning_player_id {
                     let player = self.player.borrow_mut();
-                    player.wins.extend_from_slice(&[card_on_lead, card]);
+                    player.wins.insert(card_on_lead);
+                    player.wins.insert(card);
                 }
 
                 borrowed_data.player_on_lead = winning_player_id;
@@ -367,7 +358,13 @@ impl<P, D> PlayerGame<P, D>
                 
                 let hand_empty = self.player.borrow().hand.is_empty();
                 if !will_deal && hand_empty {
-                    borrowed_data.winner = Some(winning_player_id);
+                    // If the deck was closed, the last trick does not decide
+                    // the deal: the closer has failed to reach 66.
+                    let winner = match borrowed_data.closing {
+                        Some(closing) => closing.closer.other(),
+                        None => winning_player_id
+                    };
+                    borrowed_data.winner = Some(winner);
                 }
 
                 if will_deal {
@@ -382,34 +379,31 @@ impl<P, D> PlayerGame<P, D>
     fn remove_card_from_hand(&mut self, card: Card) {
         let player = self.player.borrow_mut();
         
-        let index_option = player.hand.iter().position(
-            |&card_in_hand| card_in_hand == card);
-        index_option.map(|index| player.hand.remove(index));
+        player.hand.remove(card);
     }
 }
 
 fn legal_second_card_in_endgame(card1: Card,
-                                hand2: &[Card], card2: Card,
+                                hand2: CardSet, card2: Card,
                                 trump: Suit) -> Result<(), ErrorKind> {
     if card1.suit() == card2.suit() {
         if value(card1) < value(card2) {
             Ok(())
         } else {
-            let better_card_position = hand2.iter()
-                .position(|&other_card| other_card.suit() == card1.suit()
-                          && value(card1) < value(other_card));
+            let better_card = hand2.of_suit(card1.suit()).iter()
+                .find(|&other_card| value(card1) < value(other_card));
             
-            match better_card_position {
+            match better_card {
                 None => Ok(()),
-                Some(index) =>Err(ErrorKind::MustTake(hand2[index]))
+                Some(other_card) => Err(ErrorKind::MustTake(other_card))
             }
         }
     } else {
-        if hand2.iter().any(|&other_card| other_card.suit() == card1.suit()) {
+        if hand2.has_suit(card1.suit()) {
             Err(ErrorKind::MustUseAnotherSuit(card1.suit()))
         } else if card2.suit() == trump {
             Ok(())
-        } else if hand2.iter().any(|&other_card| other_card.suit() == trump) {
+        } else if hand2.has_suit(trump) {
             Err(ErrorKind::MustUseTrump)
         } else {
             Ok(())
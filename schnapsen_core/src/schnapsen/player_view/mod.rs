@@ -0,0 +1,73 @@
+use cards::{Card, Suit};
+use schnapsen::deal_result::DealResult;
+use schnapsen::game_data::Closing;
+use schnapsen::player::PlayerId;
+use schnapsen::trick::Trick;
+
+// Everything a player may know about the game. Of the opponent's hand, only
+// the cards revealed by a marriage or by exchanging the trump are known.
+//
+// The rules only allow looking at the last trick; earlier tricks and the
+// scores have to be remembered. The view deliberately contains both trick
+// piles anyway, as a player with perfect memory, which agents and card
+// knowledge rely on. A user interface that follows the rules strictly should
+// show only last_trick.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct PlayerView {
+    pub player_id: PlayerId,
+    pub hand: Vec<Card>,
+
+    pub trump: Suit,
+    pub trump_card: Option<Card>,
+    // The trump card that was turned face down when the deck was closed.
+    pub closed_trump_card: Option<Card>,
+    pub stock_size: u32,
+    pub closed: bool,
+    pub closing: Option<Closing>,
+
+    pub player_on_lead: PlayerId,
+    pub card_on_lead: Option<Card>,
+
+    pub own_wins: Vec<Card>,
+    pub own_twenties: Vec<Suit>,
+    pub own_forty: bool,
+
+    pub opponent_hand_size: u32,
+    pub opponent_revealed: Vec<Card>,
+    pub opponent_wins: Vec<Card>,
+    pub opponent_twenties: Vec<Suit>,
+    pub opponent_forty: bool,
+
+    // The last completed trick, the only one the rules allow to look at.
+    pub last_trick: Option<Trick>,
+
+    pub deal_result: Option<DealResult>
+}
+
+impl PlayerView {
+    pub fn opponent_id(&self) -> PlayerId {
+        self.player_id.other()
+    }
+
+    pub fn player_on_turn(&self) -> PlayerId {
+        match self.card_on_lead {
+            Some(_) => self.player_on_lead.other(),
+            None => self.player_on_lead
+        }
+    }
+
+    pub fn is_on_turn(&self) -> bool {
+        self.player_on_turn() == self.player_id
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.closed
+    }
+
+    pub fn is_game_over(&self) -> bool {
+        self.deal_result.is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests;
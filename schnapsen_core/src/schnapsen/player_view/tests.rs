@@ -0,0 +1,132 @@
+use super::*;
+use cards::Rank;
+use schnapsen::game::Game;
+use schnapsen::game_builder::GameBuilder;
+
+#[test]
+fn test_view_new_game() {
+    let game = Game::default();
+    let view = game.view_for(PlayerId::Player1);
+
+    assert_eq!(PlayerId::Player1, view.player_id);
+    assert_eq!(PlayerId::Player2, view.opponent_id());
+    assert_eq!(game.get_player1().hand.to_vec(), view.hand);
+    assert_eq!(Suit::Hearts, view.trump);
+    assert_eq!(game.trump_card(), view.trump_card);
+    assert_eq!(None, view.closed_trump_card);
+    assert_eq!(10, view.stock_size);
+    assert!(!view.is_closed());
+    assert!(view.is_on_turn());
+    assert_eq!(None, view.card_on_lead);
+    assert_eq!(5, view.opponent_hand_size);
+    assert!(view.opponent_revealed.is_empty());
+    assert!(view.own_wins.is_empty());
+    assert!(view.opponent_wins.is_empty());
+    assert!(!view.is_game_over());
+}
+
+#[test]
+fn test_view_does_not_contain_opponent_hand() {
+    let game = Game::new_seeded(3);
+
+    for &player_id in [PlayerId::Player1, PlayerId::Player2].iter() {
+        let view = game.view_for(player_id);
+        let opponent_hand = &game.get_player(player_id.other()).hand;
+
+        assert!(view.hand.iter().all(|&card| !opponent_hand.contains(card)));
+        assert!(view.opponent_revealed.is_empty());
+    }
+}
+
+#[test]
+fn test_view_twenty_reveals_partner_card() {
+    let mut game = Game::default();
+
+    let king = Card::new(Suit::Leaves, Rank::King);
+    assert!(game.play_card_twenty(king).is_ok());
+
+    let view = game.view_for(PlayerId::Player2);
+    assert!(view.is_on_turn());
+    assert_eq!(Some(king), view.card_on_lead);
+    assert_eq!(vec![Card::new(Suit::Leaves, Rank::Ober)],
+               view.opponent_revealed);
+    assert_eq!(vec![Suit::Leaves], view.opponent_twenties);
+    assert_eq!(4, view.opponent_hand_size);
+
+    let own_view = game.view_for(PlayerId::Player1);
+    assert!(!own_view.is_on_turn());
+    assert_eq!(vec![Suit::Leaves], own_view.own_twenties);
+    assert!(own_view.opponent_revealed.is_empty());
+}
+
+#[test]
+fn test_view_exchanged_trump_card_until_played() {
+    let trump_card = Card::new(Suit::Hearts, Rank::Ace);
+
+    let mut game = GameBuilder::new()
+        .hand(PlayerId::Player1,
+              vec![Card::new(Suit::Hearts, Rank::Unter),
+                   Card::new(Suit::Bells, Rank::Ace),
+                   Card::new(Suit::Bells, Rank::Ten),
+                   Card::new(Suit::Bells, Rank::King),
+                   Card::new(Suit::Bells, Rank::Ober)])
+        .hand(PlayerId::Player2,
+              vec![Card::new(Suit::Acorns, Rank::Ace),
+                   Card::new(Suit::Acorns, Rank::Ten),
+                   Card::new(Suit::Acorns, Rank::King),
+                   Card::new(Suit::Acorns, Rank::Ober),
+                   Card::new(Suit::Acorns, Rank::Unter)])
+        .trump_card(trump_card)
+        .stock(vec![Card::new(Suit::Hearts, Rank::Ober),
+                    Card::new(Suit::Hearts, Rank::King),
+                    Card::new(Suit::Hearts, Rank::Ten),
+                    Card::new(Suit::Bells, Rank::Unter),
+                    Card::new(Suit::Leaves, Rank::Unter),
+                    Card::new(Suit::Leaves, Rank::Ober),
+                    Card::new(Suit::Leaves, Rank::King),
+                    Card::new(Suit::Leaves, Rank::Ten),
+                    Card::new(Suit::Leaves, Rank::Ace)])
+        .build()
+        .unwrap();
+
+    assert!(game.exchange_trump().is_ok());
+
+    let view = game.view_for(PlayerId::Player2);
+    assert_eq!(vec![trump_card], view.opponent_revealed);
+    assert_eq!(Some(Card::new(Suit::Hearts, Rank::Unter)), view.trump_card);
+
+    assert!(game.play_card(trump_card).is_ok());
+
+    let view = game.view_for(PlayerId::Player2);
+    assert!(view.opponent_revealed.is_empty());
+}
+
+#[test]
+fn test_view_trick_piles_and_result() {
+    let mut game = Game::default();
+
+    let card1 = Card::new(Suit::Leaves, Rank::Ace);
+    let card2 = Card::new(Suit::Acorns, Rank::Ace);
+    assert!(game.play_card(card1).is_ok());
+    assert!(game.play_card(card2).is_ok());
+
+    let view = game.view_for(PlayerId::Player2);
+    assert_eq!(vec![card2, card1], view.opponent_wins);
+    assert!(view.own_wins.is_empty());
+    assert_eq!(8, view.stock_size);
+    assert_eq!(PlayerId::Player1, view.player_on_lead);
+}
+
+#[test]
+fn test_view_closed() {
+    let mut game = Game::default();
+    let trump_card = game.trump_card();
+
+    assert!(game.close().is_ok());
+
+    let view = game.view_for(PlayerId::Player2);
+    assert!(view.is_closed());
+    assert_eq!(None, view.trump_card);
+    assert_eq!(trump_card, view.closed_trump_card);
+    assert_eq!(game.closing(), view.closing);
+}
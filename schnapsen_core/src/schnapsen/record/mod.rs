@@ -0,0 +1,297 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use cards::{format_cards, parse_cards, Card};
+use schnapsen::{generate_seeded_deck, validate_deck, DeckError, ErrorKind};
+use schnapsen::deal_result::{DealEndReason, DealResult};
+use schnapsen::game::Game;
+use schnapsen::moves::{Move, ParseMoveError};
+use schnapsen::player::PlayerId;
+use schnapsen::replay::Replay;
+
+// A plain-text record of a deal, similar to PGN. Tag lines come first,
+// followed by the moves in move notation separated by whitespace:
+//
+//     [Seed "42"]
+//     [Deck "HU HO HK ..."]
+//     [Lead "Player1"]
+//     [Player1 "Alice"]
+//     [Player2 "Bob"]
+//     [Result "Player1 2 LastTrick"]
+//
+//     LA AU close LO ...
+//
+// The deck is listed in the order of Game::from_deck. If it is missing, it is
+// generated from the seed; if both are given, they must agree. The result is
+// "*" while the deal is not over. Unknown tags are ignored.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GameRecord {
+    pub seed: Option<u64>,
+    pub deck: Vec<Card>,
+    pub player_on_lead: PlayerId,
+    pub player1: String,
+    pub player2: String,
+    pub moves: Vec<Move>,
+    pub result: Option<DealResult>
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RecordError {
+    // The line number starts at 1.
+    MalformedTag(usize),
+    MissingDeck,
+    InvalidValue(String, String),
+    InvalidDeck(DeckError),
+    // The move index starts at 0.
+    InvalidMove(usize, ParseMoveError),
+    IllegalMove(usize, ErrorKind),
+    ResultMismatch
+}
+
+impl fmt::Display for RecordError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            RecordError::MalformedTag(line)
+                => write!(f, "malformed tag in line {}", line),
+            RecordError::MissingDeck
+                => write!(f, "the record has neither a deck nor a seed"),
+            RecordError::InvalidValue(ref tag, ref value)
+                => write!(f, "invalid value \"{}\" for tag {}", value, tag),
+            RecordError::InvalidDeck(error)
+                => write!(f, "invalid deck: {:?}", error),
+            RecordError::InvalidMove(index, ref error)
+                => write!(f, "move {}: {}", index + 1, error),
+            RecordError::IllegalMove(index, error)
+                => write!(f, "move {} is illegal: {:?}", index + 1, error),
+            RecordError::ResultMismatch
+                => write!(f, "the moves do not lead to the recorded result")
+        }
+    }
+}
+
+impl Error for RecordError {}
+
+impl GameRecord {
+    // None if the game was not dealt from a deck, e.g. set up with the
+    // GameBuilder.
+    pub fn from_game(game: &Game) -> Option<GameRecord> {
+        game.origin().map(|origin| GameRecord {
+            seed: origin.seed,
+            deck: origin.deck.clone(),
+            player_on_lead: origin.player_on_lead,
+            player1: game.get_player1().name().to_string(),
+            player2: game.get_player2().name().to_string(),
+            moves: game.moves().to_vec(),
+            result: game.deal_result()
+        })
+    }
+
+    // Replays the moves so that every intermediate position can be
+    // inspected.
+    pub fn to_replay(&self) -> Result<Replay, RecordError> {
+        validate_deck(&self.deck).map_err(RecordError::InvalidDeck)?;
+
+        let mut game = Game::new_with_lead(self.deck.clone(),
+                                           self.player_on_lead).unwrap();
+        game.set_player_name(PlayerId::Player1, &self.player1);
+        game.set_player_name(PlayerId::Player2, &self.player2);
+
+        Ok(Replay::from_game(game, self.moves.clone()))
+    }
+
+    // Deals the deck and replays the moves.
+    pub fn to_game(&self) -> Result<Game, RecordError> {
+        let replay = self.to_replay()?;
+
+        if let Some((index, illegal_move)) = replay.illegal_move() {
+            return Err(RecordError::IllegalMove(index, illegal_move.error));
+        }
+
+        let game = replay.final_game();
+        if game.deal_result() != self.result {
+            return Err(RecordError::ResultMismatch);
+        }
+
+        Ok(game.clone())
+    }
+}
+
+impl fmt::Display for GameRecord {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(seed) = self.seed {
+            write_tag(f, "Seed", &seed.to_string())?;
+        }
+
+        write_tag(f, "Deck", &format_cards(&self.deck))?;
+        write_tag(f, "Lead", &format!("{:?}", self.player_on_lead))?;
+        write_tag(f, "Player1", &self.player1)?;
+        write_tag(f, "Player2", &self.player2)?;
+
+        let result = match self.result {
+            Some(result) => format!("{:?} {} {:?}", result.winner,
+                                    result.game_points, result.reason),
+            None => "*".to_string()
+        };
+        write_tag(f, "Result", &result)?;
+
+        // The moves are wrapped at 80 characters.
+        let mut line = String::new();
+        writeln!(f)?;
+        for mv in &self.moves {
+            let text = mv.to_string();
+            if !line.is_empty() && line.len() + 1 + text.len() > 80 {
+                writeln!(f, "{}", line)?;
+                line.clear();
+            }
+
+            if !line.is_empty() {
+                line.push(' ');
+            }
+            line.push_str(&text);
+        }
+
+        if !line.is_empty() {
+            writeln!(f, "{}", line)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for GameRecord {
+    type Err = RecordError;
+
+    fn from_str(s: &str) -> Result<GameRecord, RecordError> {
+        let mut seed = None;
+        let mut deck = None;
+        let mut record = GameRecord {
+            seed: None, deck: Vec::new(), player_on_lead: PlayerId::Player1,
+            player1: "Player1".to_string(), player2: "Player2".to_string(),
+            moves: Vec::new(), result: None
+        };
+        let mut move_tokens = Vec::new();
+
+        for (index, line) in s.lines().enumerate() {
+            let line = line.trim();
+
+            if !line.starts_with('[') {
+                move_tokens.extend(line.split_whitespace());
+                continue;
+            }
+
+            let (tag, value) = parse_tag(line)
+                .ok_or(RecordError::MalformedTag(index + 1))?;
+            let invalid = || RecordError::InvalidValue(tag.to_string(),
+                                                       value.clone());
+
+            match tag {
+                "Seed" => seed = Some(value.parse().map_err(|_| invalid())?),
+                "Deck" => deck = Some(parse_cards(&value)
+                                      .map_err(|_| invalid())?),
+                "Lead" => record.player_on_lead = parse_player(&value)
+                    .ok_or_else(invalid)?,
+                "Player1" => record.player1 = value.clone(),
+                "Player2" => record.player2 = value.clone(),
+                "Result" => record.result = parse_result(&value)
+                    .ok_or_else(invalid)?,
+                _ => {}
+            }
+        }
+
+        record.seed = seed;
+        record.deck = match (deck, seed) {
+            (Some(deck), Some(seed)) => {
+                // A seed that does not produce the deck contradicts it.
+                if generate_seeded_deck(seed) != deck {
+                    return Err(RecordError::InvalidValue("Seed".to_string(),
+                                                         seed.to_string()));
+                }
+                deck
+            },
+            (Some(deck), None) => deck,
+            (None, Some(seed)) => generate_seeded_deck(seed),
+            (None, None) => return Err(RecordError::MissingDeck)
+        };
+
+        for (index, token) in move_tokens.into_iter().enumerate() {
+            let mv = token.parse()
+                .map_err(|error| RecordError::InvalidMove(index, error))?;
+            record.moves.push(mv);
+        }
+
+        Ok(record)
+    }
+}
+
+// Line breaks are escaped as well, as every tag has to fit on one line.
+fn write_tag(f: &mut fmt::Formatter, tag: &str, value: &str) -> fmt::Result {
+    let escaped = value.replace('\\', "\\\\").replace('"', "\\\"")
+        .replace('\n', "\\n").replace('\r', "\\r");
+    writeln!(f, "[{} \"{}\"]", tag, escaped)
+}
+
+fn parse_tag(line: &str) -> Option<(&str, String)> {
+    if !line.ends_with(']') || line.len() < 2 {
+        return None;
+    }
+
+    let inner = &line[1..line.len() - 1];
+    let space = inner.find(' ')?;
+    let (tag, rest) = inner.split_at(space);
+    let rest = rest.trim();
+
+    if tag.is_empty() || rest.len() < 2 || !rest.starts_with('"')
+        || !rest.ends_with('"') {
+        return None;
+    }
+
+    let mut value = String::new();
+    let mut chars = rest[1..rest.len() - 1].chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => value.push(match chars.next()? {
+                'n' => '\n',
+                'r' => '\r',
+                escaped => escaped
+            }),
+            '"' => return None,
+            _ => value.push(c)
+        }
+    }
+
+    Some((tag, value))
+}
+
+fn parse_player(value: &str) -> Option<PlayerId> {
+    match value {
+        "Player1" => Some(PlayerId::Player1),
+        "Player2" => Some(PlayerId::Player2),
+        _ => None
+    }
+}
+
+fn parse_result(value: &str) -> Option<Option<DealResult>> {
+    if value == "*" {
+        return Some(None);
+    }
+
+    let parts: Vec<&str> = value.split_whitespace().collect();
+    if parts.len() != 3 {
+        return None;
+    }
+
+    let winner = parse_player(parts[0])?;
+    let game_points = parts[1].parse().ok()?;
+    let reason = match parts[2] {
+        "DeclaredWin" => DealEndReason::DeclaredWin,
+        "LastTrick" => DealEndReason::LastTrick,
+        "CloserFailed" => DealEndReason::CloserFailed,
+        _ => return None
+    };
+
+    Some(Some(DealResult {winner, game_points, reason}))
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,150 @@
+use super::*;
+use schnapsen::agent::RandomAgent;
+use schnapsen::game_builder::GameBuilder;
+use schnapsen::runner::run_deal;
+use cards::{Rank, Suit};
+
+fn played_game(seed: u64) -> Game {
+    let mut game = Game::new_seeded(seed);
+    run_deal(&mut game, &mut RandomAgent::new_seeded(seed),
+             &mut RandomAgent::new_seeded(seed + 1)).unwrap();
+    game
+}
+
+#[test]
+fn test_record_round_trip() {
+    for seed in 0..10 {
+        let mut game = played_game(seed);
+        game.set_player_name(PlayerId::Player1, "Alice");
+
+        let record = GameRecord::from_game(&game).unwrap();
+        assert_eq!(Some(seed), record.seed);
+        assert_eq!("Alice", record.player1);
+        assert_eq!(game.moves(), &record.moves[..]);
+        assert_eq!(game.deal_result(), record.result);
+
+        let text = record.to_string();
+        let parsed: GameRecord = text.parse().unwrap();
+        assert_eq!(record, parsed);
+
+        let replayed = parsed.to_game().unwrap();
+        assert_eq!(game, replayed);
+        assert_eq!(game.deal_result(), replayed.deal_result());
+        assert_eq!("Alice", replayed.get_player1().name());
+    }
+}
+
+#[test]
+fn test_record_unfinished_game_with_other_lead() {
+    let deck = generate_seeded_deck(5);
+    let mut game = Game::new_with_lead(deck.clone(), PlayerId::Player2)
+        .unwrap();
+    let mv = game.legal_moves()[0];
+    assert!(game.apply(mv).is_ok());
+
+    let record = GameRecord::from_game(&game).unwrap();
+    let text = record.to_string();
+    assert!(text.contains("[Lead \"Player2\"]"));
+    assert!(text.contains("[Result \"*\"]"));
+    assert!(!text.contains("[Seed"));
+
+    let parsed: GameRecord = text.parse().unwrap();
+    assert_eq!(deck, parsed.deck);
+    assert_eq!(game, parsed.to_game().unwrap());
+}
+
+#[test]
+fn test_record_seed_without_deck() {
+    let text = "[Seed \"7\"]\n[Result \"*\"]\n\n";
+    let record: GameRecord = text.parse().unwrap();
+
+    assert_eq!(generate_seeded_deck(7), record.deck);
+    assert_eq!(Game::new_seeded(7), record.to_game().unwrap());
+}
+
+#[test]
+fn test_record_escapes_names() {
+    let mut game = Game::new_seeded(1);
+    game.set_player_name(PlayerId::Player2, "The \"Best\" \\ Player");
+
+    let record = GameRecord::from_game(&game).unwrap();
+    let parsed: GameRecord = record.to_string().parse().unwrap();
+
+    assert_eq!("The \"Best\" \\ Player", parsed.player2);
+}
+
+#[test]
+fn test_record_escapes_line_breaks() {
+    let mut game = Game::new_seeded(1);
+    game.set_player_name(PlayerId::Player1, "Alice]\n[Seed \"2\"]\r\nn");
+
+    let record = GameRecord::from_game(&game).unwrap();
+    let text = record.to_string();
+    let parsed: GameRecord = text.parse().unwrap();
+
+    assert_eq!(record, parsed);
+    assert_eq!("Alice]\n[Seed \"2\"]\r\nn", parsed.player1);
+    assert_eq!(Some(1), parsed.seed);
+}
+
+#[test]
+fn test_record_not_dealt_from_deck() {
+    let game = GameBuilder::new()
+        .hand(PlayerId::Player1, vec![Card::new(Suit::Hearts, Rank::Ace)])
+        .hand(PlayerId::Player2, vec![Card::new(Suit::Hearts, Rank::Ten)])
+        .wins(PlayerId::Player1, ::schnapsen::generate_deck().into_iter()
+              .filter(|card| card.rank() != Rank::Ace
+                      || card.suit() != Suit::Hearts)
+              .filter(|card| card.rank() != Rank::Ten
+                      || card.suit() != Suit::Hearts)
+              .collect())
+        .trump(Suit::Hearts)
+        .build()
+        .unwrap();
+
+    assert_eq!(None, GameRecord::from_game(&game));
+}
+
+#[test]
+fn test_record_errors() {
+    assert_eq!(Err(RecordError::MissingDeck), "HA".parse::<GameRecord>());
+    assert_eq!(Err(RecordError::MalformedTag(2)),
+               "[Seed \"1\"]\n[Result *]".parse::<GameRecord>());
+    assert_eq!(Err(RecordError::InvalidValue("Seed".to_string(),
+                                             "x".to_string())),
+               "[Seed \"x\"]".parse::<GameRecord>());
+
+    let deck = format_cards(&generate_seeded_deck(2));
+    let contradicting = format!("[Seed \"1\"]\n[Deck \"{}\"]", deck);
+    assert_eq!(Err(RecordError::InvalidValue("Seed".to_string(),
+                                             "1".to_string())),
+               contradicting.parse::<GameRecord>());
+    assert_eq!(Err(RecordError::InvalidMove(
+        1, ParseMoveError::UnknownMove("winner".to_string()))),
+               "[Seed \"1\"]\nclose winner".parse::<GameRecord>());
+
+    let record: GameRecord = "[Seed \"1\"]\nwin".parse().unwrap();
+    assert_eq!(Err(RecordError::IllegalMove(0, ErrorKind::ScoreTooLow(0))),
+               record.to_game());
+
+    let mut record = GameRecord::from_game(&played_game(2)).unwrap();
+    record.result = None;
+    assert_eq!(Err(RecordError::ResultMismatch), record.to_game());
+
+    record.deck.pop();
+    assert_eq!(Err(RecordError::InvalidDeck(DeckError::WrongSize(19))),
+               record.to_game());
+}
+
+#[test]
+fn test_record_to_replay() {
+    let game = played_game(3);
+    let record = GameRecord::from_game(&game).unwrap();
+
+    let mut replay = record.to_replay().unwrap();
+    assert_eq!(game.moves().len(), replay.len());
+    assert_eq!(record.player1, replay.game().get_player1().name());
+
+    replay.to_end();
+    assert_eq!(&game, replay.game());
+}
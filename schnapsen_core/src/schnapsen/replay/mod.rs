@@ -0,0 +1,142 @@
+use cards::Card;
+use schnapsen::{validate_deck, DeckError};
+use schnapsen::game::Game;
+use schnapsen::moves::Move;
+use schnapsen::player::PlayerId;
+use schnapsen::runner::IllegalMove;
+
+// Replays a list of moves from a deck and keeps every position on the way,
+// so that the replay can be stepped through in both directions. Replaying
+// stops at the first illegal move.
+#[derive(Clone, Debug)]
+pub struct Replay {
+    positions: Vec<Game>,
+    moves: Vec<Move>,
+    // The first move that the game rejected and its index in the moves.
+    illegal_move: Option<(usize, IllegalMove)>,
+    current: usize
+}
+
+impl Replay {
+    // The deck is dealt as by Game::from_deck. The replay starts at the
+    // initial position.
+    pub fn new(deck: Vec<Card>, player_on_lead: PlayerId, moves: Vec<Move>)
+               -> Result<Replay, DeckError> {
+        validate_deck(&deck)?;
+        let game = Game::new_with_lead(deck, player_on_lead).unwrap();
+        Ok(Replay::from_game(game, moves))
+    }
+
+    // Replays the moves from an arbitrary position.
+    pub fn from_game(game: Game, moves: Vec<Move>) -> Replay {
+        let mut positions = vec![game];
+        let mut illegal_move = None;
+
+        for (index, &mv) in moves.iter().enumerate() {
+            let mut game = positions.last().unwrap().clone();
+            let player = game.player_on_turn();
+
+            let result = game.can_apply(mv).and_then(|()| game.apply(mv));
+            match result {
+                Ok(()) => positions.push(game),
+                Err(error) => {
+                    illegal_move = Some((index, IllegalMove {player, mv,
+                                                             error}));
+                    break;
+                }
+            }
+        }
+
+        Replay {positions, moves, illegal_move, current: 0}
+    }
+
+    // All moves of the replay, including those after an illegal move.
+    pub fn moves(&self) -> &[Move] {
+        &self.moves
+    }
+
+    pub fn illegal_move(&self) -> Option<(usize, IllegalMove)> {
+        self.illegal_move
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.illegal_move.is_none()
+    }
+
+    // The number of moves that could be replayed.
+    pub fn len(&self) -> usize {
+        self.positions.len() - 1
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    // The number of moves made to reach the current position.
+    pub fn current_index(&self) -> usize {
+        self.current
+    }
+
+    pub fn game(&self) -> &Game {
+        &self.positions[self.current]
+    }
+
+    // The position after the given number of moves.
+    pub fn game_at(&self, index: usize) -> Option<&Game> {
+        self.positions.get(index)
+    }
+
+    pub fn final_game(&self) -> &Game {
+        self.positions.last().unwrap()
+    }
+
+    // The move that leads from the current position to the next one.
+    pub fn next_move(&self) -> Option<Move> {
+        if self.current < self.len() {
+            Some(self.moves[self.current])
+        } else {
+            None
+        }
+    }
+
+    // Returns the move that was made, None at the end of the replay.
+    pub fn step_forward(&mut self) -> Option<Move> {
+        let mv = self.next_move();
+        if mv.is_some() {
+            self.current += 1;
+        }
+
+        mv
+    }
+
+    // Returns the move that was taken back, None at the start.
+    pub fn step_backward(&mut self) -> Option<Move> {
+        if self.current == 0 {
+            return None;
+        }
+
+        self.current -= 1;
+        Some(self.moves[self.current])
+    }
+
+    // Returns false if there is no position with the given index.
+    pub fn seek(&mut self, index: usize) -> bool {
+        if index <= self.len() {
+            self.current = index;
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn to_start(&mut self) {
+        self.current = 0;
+    }
+
+    pub fn to_end(&mut self) {
+        self.current = self.len();
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,106 @@
+use super::*;
+use cards::{Rank, Suit};
+use schnapsen::{generate_seeded_deck, ErrorKind};
+
+fn recorded_moves(seed: u64) -> Vec<Move> {
+    let mut game = Game::new_seeded(seed);
+    let mut index = seed as usize;
+
+    while !game.is_game_over() {
+        let moves = game.legal_moves();
+        index += 3;
+        assert!(game.apply(moves[index % moves.len()]).is_ok());
+    }
+
+    game.moves().to_vec()
+}
+
+#[test]
+fn test_replay_valid_game() {
+    let moves = recorded_moves(4);
+    let replay = Replay::new(generate_seeded_deck(4), PlayerId::Player1,
+                             moves.clone()).unwrap();
+
+    assert!(replay.is_valid());
+    assert_eq!(None, replay.illegal_move());
+    assert_eq!(moves.len(), replay.len());
+    assert_eq!(0, replay.current_index());
+    assert_eq!(&Game::new_seeded(4), replay.game());
+    assert!(replay.final_game().is_game_over());
+    assert_eq!(moves, replay.final_game().moves());
+}
+
+#[test]
+fn test_replay_stepping() {
+    let moves = recorded_moves(6);
+    let mut replay = Replay::new(generate_seeded_deck(6), PlayerId::Player1,
+                                 moves.clone()).unwrap();
+
+    assert_eq!(None, replay.step_backward());
+
+    for (index, &mv) in moves.iter().enumerate() {
+        assert_eq!(Some(mv), replay.next_move());
+        assert_eq!(Some(mv), replay.step_forward());
+        assert_eq!(index + 1, replay.current_index());
+        assert_eq!(&moves[..index + 1], replay.game().moves());
+    }
+
+    assert_eq!(None, replay.step_forward());
+    assert_eq!(replay.final_game(), replay.game());
+
+    let last = *moves.last().unwrap();
+    assert_eq!(Some(last), replay.step_backward());
+    assert_eq!(moves.len() - 1, replay.current_index());
+    assert_eq!(replay.game_at(moves.len() - 1), Some(replay.game()));
+
+    assert!(replay.seek(2));
+    assert_eq!(2, replay.game().moves().len());
+    assert!(!replay.seek(moves.len() + 1));
+    assert_eq!(2, replay.current_index());
+
+    replay.to_end();
+    assert_eq!(moves.len(), replay.current_index());
+    replay.to_start();
+    assert_eq!(0, replay.current_index());
+}
+
+#[test]
+fn test_replay_first_illegal_move() {
+    // Game::default(): Player1 holds the Leaves, Player2 the Acorns.
+    let leaves_ace = Card::new(Suit::Leaves, Rank::Ace);
+    let acorns_ace = Card::new(Suit::Acorns, Rank::Ace);
+    let moves = vec![Move::PlayCard(leaves_ace), Move::PlayCard(acorns_ace),
+                     Move::PlayCard(leaves_ace), Move::DeclareWin];
+
+    let replay = Replay::from_game(Game::default(), moves.clone());
+
+    let expected = IllegalMove {player: PlayerId::Player1,
+                                mv: Move::PlayCard(leaves_ace),
+                                error: ErrorKind::NoSuchCardInHand(leaves_ace)};
+    assert_eq!(Some((2, expected)), replay.illegal_move());
+    assert!(!replay.is_valid());
+    assert_eq!(2, replay.len());
+    assert_eq!(moves, replay.moves());
+}
+
+#[test]
+fn test_replay_move_after_game_over() {
+    let mut moves = recorded_moves(2);
+    moves.push(Move::DeclareWin);
+
+    let replay = Replay::new(generate_seeded_deck(2), PlayerId::Player1,
+                             moves.clone()).unwrap();
+
+    let (index, illegal_move) = replay.illegal_move().unwrap();
+    assert_eq!(moves.len() - 1, index);
+    assert_eq!(ErrorKind::GameOver, illegal_move.error);
+}
+
+#[test]
+fn test_replay_invalid_deck() {
+    let mut deck = generate_seeded_deck(1);
+    deck.pop();
+
+    assert_eq!(Err(DeckError::WrongSize(19)),
+               Replay::new(deck, PlayerId::Player1, Vec::new()).map(|_| ()));
+}
@@ -0,0 +1,49 @@
+use schnapsen::ErrorKind;
+use schnapsen::agent::Agent;
+use schnapsen::deal_result::DealResult;
+use schnapsen::game::Game;
+use schnapsen::moves::Move;
+use schnapsen::player::PlayerId;
+
+// The result of a deal played to the end and every move in order.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct DealRecord {
+    pub result: DealResult,
+    pub moves: Vec<(PlayerId, Move)>
+}
+
+// A move that the game rejected, e.g. one returned by an agent.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct IllegalMove {
+    pub player: PlayerId,
+    pub mv: Move,
+    pub error: ErrorKind
+}
+
+// Lets the agents play the game until the deal is over. The game is left in
+// its final state, or in the state where the illegal move was attempted.
+pub fn run_deal(game: &mut Game, agent1: &mut dyn Agent,
+                agent2: &mut dyn Agent) -> Result<DealRecord, IllegalMove> {
+    let mut moves = Vec::new();
+
+    while !game.is_game_over() {
+        let player = game.player_on_turn();
+        let view = game.view_for(player);
+        let legal_moves = game.legal_moves();
+
+        let mv = match player {
+            PlayerId::Player1 => agent1.choose_move(&view, &legal_moves),
+            PlayerId::Player2 => agent2.choose_move(&view, &legal_moves)
+        };
+
+        game.apply(mv).map_err(|error| IllegalMove {player, mv, error})?;
+        moves.push((player, mv));
+    }
+
+    let result = game.deal_result()
+        .expect("a finished game should have a result");
+    Ok(DealRecord {result, moves})
+}
+
+#[cfg(test)]
+mod tests;
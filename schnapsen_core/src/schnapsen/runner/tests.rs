@@ -0,0 +1,67 @@
+use super::*;
+use schnapsen::agent::RandomAgent;
+use schnapsen::player_view::PlayerView;
+
+struct FirstCardAgent;
+
+impl Agent for FirstCardAgent {
+    fn choose_move(&mut self, view: &PlayerView, _legal_moves: &[Move])
+                   -> Move {
+        Move::PlayCard(view.hand[0])
+    }
+}
+
+#[test]
+fn test_run_deal_random_agents() {
+    for seed in 0..10 {
+        let mut game = Game::new_seeded(seed);
+        let mut agent1 = RandomAgent::new_seeded(seed);
+        let mut agent2 = RandomAgent::new_seeded(seed + 1);
+
+        let record = run_deal(&mut game, &mut agent1, &mut agent2).unwrap();
+
+        assert!(game.is_game_over());
+        assert_eq!(game.deal_result(), Some(record.result));
+        assert!(!record.moves.is_empty());
+    }
+}
+
+#[test]
+fn test_run_deal_move_log_replays() {
+    let mut game = Game::new_seeded(3);
+    let record = run_deal(&mut game, &mut RandomAgent::new_seeded(1),
+                          &mut RandomAgent::new_seeded(2)).unwrap();
+
+    let mut replay = Game::new_seeded(3);
+    for &(player, mv) in &record.moves {
+        assert_eq!(player, replay.player_on_turn());
+        assert!(replay.apply(mv).is_ok());
+    }
+
+    assert_eq!(Some(record.result), replay.deal_result());
+}
+
+// Answers the card on lead with the same card, which is never in its hand.
+struct CopyLeadAgent;
+
+impl Agent for CopyLeadAgent {
+    fn choose_move(&mut self, view: &PlayerView, legal_moves: &[Move])
+                   -> Move {
+        view.card_on_lead.map(Move::PlayCard).unwrap_or(legal_moves[0])
+    }
+}
+
+#[test]
+fn test_run_deal_illegal_move() {
+    let mut game = Game::default();
+    let lead_card = game.get_player1().hand.to_vec()[0];
+
+    let error = run_deal(&mut game, &mut FirstCardAgent, &mut CopyLeadAgent)
+        .unwrap_err();
+
+    let expected = IllegalMove {player: PlayerId::Player2,
+                                mv: Move::PlayCard(lead_card),
+                                error: ErrorKind::NoSuchCardInHand(lead_card)};
+    assert_eq!(expected, error);
+    assert_eq!(PlayerId::Player2, game.player_on_turn());
+}
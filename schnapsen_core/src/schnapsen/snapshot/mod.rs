@@ -0,0 +1,55 @@
+use serde_json;
+
+use schnapsen::game::Game;
+
+// Has to be increased whenever the serialized form of Game changes.
+pub const SNAPSHOT_VERSION: u32 = 1;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SnapshotError {
+    Malformed(String),
+    UnsupportedVersion(u32)
+}
+
+#[derive(Serialize)]
+struct SnapshotRef<'a> {
+    version: u32,
+    game: &'a Game
+}
+
+#[derive(Deserialize)]
+struct SnapshotHeader {
+    version: u32
+}
+
+#[derive(Deserialize)]
+struct Snapshot {
+    game: Game
+}
+
+impl Game {
+    // A JSON snapshot of the complete state of the game.
+    pub fn to_snapshot(&self) -> String {
+        let snapshot = SnapshotRef {version: SNAPSHOT_VERSION, game: self};
+
+        // Serializing cannot fail: all map keys are strings.
+        serde_json::to_string(&snapshot).unwrap()
+    }
+
+    pub fn from_snapshot(snapshot: &str) -> Result<Game, SnapshotError> {
+        let header: SnapshotHeader = serde_json::from_str(snapshot)
+            .map_err(|err| SnapshotError::Malformed(err.to_string()))?;
+
+        if header.version != SNAPSHOT_VERSION {
+            return Err(SnapshotError::UnsupportedVersion(header.version));
+        }
+
+        let snapshot: Snapshot = serde_json::from_str(snapshot)
+            .map_err(|err| SnapshotError::Malformed(err.to_string()))?;
+
+        Ok(snapshot.game)
+    }
+}
+
+#[cfg(test)]
+mod tests;
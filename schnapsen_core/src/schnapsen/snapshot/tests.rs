@@ -0,0 +1,105 @@
+use super::*;
+use cards::{Card, Suit, Rank};
+use schnapsen::PlayerId;
+
+fn play_moves(game: &mut Game, count: usize) {
+    for index in 0..count {
+        let moves = game.legal_moves();
+        if moves.is_empty() {
+            return;
+        }
+
+        let mv = moves[index % moves.len()];
+        assert!(game.apply(mv).is_ok());
+    }
+}
+
+#[test]
+fn test_round_trip_new_game() {
+    let game = Game::new_seeded(11);
+
+    let snapshot = game.to_snapshot();
+    let restored = Game::from_snapshot(&snapshot).unwrap();
+
+    assert_eq!(snapshot, restored.to_snapshot());
+}
+
+#[test]
+fn test_round_trip_mid_game() {
+    let mut game = Game::new_seeded(5);
+    play_moves(&mut game, 7);
+    assert!(!game.is_game_over());
+
+    let snapshot = game.to_snapshot();
+    let mut restored = Game::from_snapshot(&snapshot).unwrap();
+
+    assert_eq!(snapshot, restored.to_snapshot());
+    assert_eq!(game.legal_moves(), restored.legal_moves());
+    assert_eq!(game.events(), restored.events());
+    assert_eq!(game.closing(), restored.closing());
+
+    // Both games continue in exactly the same way.
+    play_moves(&mut game, 100);
+    play_moves(&mut restored, 100);
+
+    assert!(game.is_game_over());
+    assert_eq!(game.deal_result(), restored.deal_result());
+    assert_eq!(game.to_snapshot(), restored.to_snapshot());
+}
+
+#[test]
+fn test_round_trip_finished_game() {
+    let mut game = Game::new_seeded(8);
+    play_moves(&mut game, 100);
+
+    let restored = Game::from_snapshot(&game.to_snapshot()).unwrap();
+
+    assert!(restored.is_game_over());
+    assert_eq!(game.deal_result(), restored.deal_result());
+    assert_eq!(game.winner(), restored.winner());
+}
+
+#[test]
+fn test_round_trip_player_data() {
+    let mut game = Game::default();
+    assert!(game.play_card_twenty(Card::new(Suit::Leaves, Rank::King)).is_ok());
+
+    let restored = Game::from_snapshot(&game.to_snapshot()).unwrap();
+
+    let player = restored.get_player(PlayerId::Player1);
+    assert_eq!(vec![Suit::Leaves], player.twenties);
+    assert_eq!(4, player.hand.len());
+    assert_eq!(game.get_player1().name, player.name);
+}
+
+#[test]
+fn test_unsupported_version() {
+    let snapshot = Game::default().to_snapshot()
+        .replacen(&format!("\"version\":{}", SNAPSHOT_VERSION),
+                  "\"version\":999", 1);
+
+    assert_eq!(Err(SnapshotError::UnsupportedVersion(999)),
+               Game::from_snapshot(&snapshot).map(|_| ()));
+}
+
+#[test]
+fn test_malformed_snapshot() {
+    let result = Game::from_snapshot("{\"version\":1}");
+
+    match result {
+        Err(SnapshotError::Malformed(_)) => (),
+        _ => panic!("expected a malformed snapshot error")
+    }
+}
+
+#[test]
+fn test_snapshot_restores_hash() {
+    let mut game = Game::new_seeded(8);
+    let mv = game.legal_moves()[0];
+    assert!(game.apply(mv).is_ok());
+
+    let restored = Game::from_snapshot(&game.to_snapshot()).unwrap();
+
+    assert_eq!(game.zobrist_hash(), restored.zobrist_hash());
+    assert_eq!(game, restored);
+}
@@ -0,0 +1,155 @@
+use std::cmp;
+
+use schnapsen::deal_result::DealResult;
+use schnapsen::game::Game;
+use schnapsen::moves::Move;
+use schnapsen::player::PlayerId;
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Solution {
+    pub best_move: Move,
+    // The game points won (positive) or lost (negative) by the player on
+    // turn if both players play perfectly.
+    pub value: i32
+}
+
+// Once the deck is closed or the stock is empty, both players know all the
+// cards and the deal can be solved exactly.
+pub fn is_endgame(game: &Game) -> bool {
+    !game.is_game_over() && (game.is_closed() || game.stock_size() == 0)
+}
+
+// Solves an endgame with alpha-beta search. Returns None if the game is not
+// in the endgame.
+pub fn solve(game: &Game) -> Option<Solution> {
+    if !is_endgame(game) {
+        return None;
+    }
+
+    let player = game.player_on_turn();
+    let mut best: Option<Solution> = None;
+    let mut game = game.clone();
+
+    for mv in ordered_moves(&game) {
+        let alpha = best.map(|solution| solution.value).unwrap_or(MIN_VALUE);
+        game.apply(mv).unwrap();
+        let value = search(&mut game, player, alpha, MAX_VALUE);
+        game.undo();
+
+        if best.map(|solution| value > solution.value).unwrap_or(true) {
+            best = Some(Solution {best_move: mv, value});
+        }
+    }
+
+    best
+}
+
+const MIN_VALUE: i32 = -4;
+const MAX_VALUE: i32 = 4;
+
+// The moves are made and taken back on the same game instead of cloning it.
+fn search(game: &mut Game, player: PlayerId, mut alpha: i32, mut beta: i32)
+          -> i32 {
+    if let Some(result) = game.deal_result() {
+        return outcome(&result, player);
+    }
+
+    let maximizing = game.player_on_turn() == player;
+
+    for mv in ordered_moves(game) {
+        game.apply(mv).unwrap();
+        let value = search(game, player, alpha, beta);
+        game.undo();
+
+        if maximizing {
+            alpha = cmp::max(alpha, value);
+        } else {
+            beta = cmp::min(beta, value);
+        }
+
+        if alpha >= beta {
+            break;
+        }
+    }
+
+    if maximizing {
+        alpha
+    } else {
+        beta
+    }
+}
+
+// The game points won by the given player, negative if the player lost.
+pub(super) fn outcome(result: &DealResult, player: PlayerId) -> i32 {
+    let game_points = result.game_points as i32;
+
+    if result.winner == player {
+        game_points
+    } else {
+        -game_points
+    }
+}
+
+// Ending the deal is tried first as it is often the best move and cuts the
+// search short.
+fn ordered_moves(game: &Game) -> Vec<Move> {
+    let mut moves = game.legal_moves();
+    moves.sort_by_key(|&mv| match mv {
+        Move::DeclareWin | Move::DeclareTwentyWin(_)
+            | Move::DeclareFortyWin => 0,
+        Move::PlayTwenty(_) | Move::PlayForty(_) => 1,
+        _ => 2
+    });
+
+    moves
+}
+
+// Evaluates the game for the given player in hundredths of game points. The
+// search stops after the given number of moves unless the game is in the
+// endgame, where it is solved exactly. Unfinished games are rated by the
+// difference of the scores, which is always less than a game point.
+pub fn evaluate(game: &Game, player: PlayerId, depth: u32) -> i32 {
+    search_limited(&mut game.clone(), player, depth, MIN_VALUE * 100,
+                   MAX_VALUE * 100)
+}
+
+fn search_limited(game: &mut Game, player: PlayerId, depth: u32,
+                  mut alpha: i32, mut beta: i32) -> i32 {
+    if let Some(result) = game.deal_result() {
+        return outcome(&result, player) * 100;
+    }
+
+    if depth == 0 && !is_endgame(game) {
+        let score = game.get_player(player).score() as i32;
+        let opponent_score = game.get_player(player.other()).score() as i32;
+        return (score - opponent_score).clamp(-99, 99);
+    }
+
+    let maximizing = game.player_on_turn() == player;
+    let next_depth = depth.saturating_sub(1);
+
+    for mv in ordered_moves(game) {
+        game.apply(mv).unwrap();
+        let value = search_limited(game, player, next_depth, alpha, beta);
+        game.undo();
+
+        if maximizing {
+            alpha = cmp::max(alpha, value);
+        } else {
+            beta = cmp::min(beta, value);
+        }
+
+        if alpha >= beta {
+            break;
+        }
+    }
+
+    if maximizing {
+        alpha
+    } else {
+        beta
+    }
+}
+
+#[cfg(test)]
+mod tests;
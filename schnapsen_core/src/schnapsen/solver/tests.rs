@@ -0,0 +1,165 @@
+use super::*;
+use cards::{Card, Suit, Rank};
+use schnapsen::game_builder::GameBuilder;
+
+fn after_move(game: &Game, mv: Move) -> Game {
+    let mut next = game.clone();
+    assert!(next.apply(mv).is_ok());
+    next
+}
+
+fn minimax(game: &Game, player: PlayerId) -> i32 {
+    if let Some(result) = game.deal_result() {
+        return outcome(&result, player);
+    }
+
+    let values = game.legal_moves().into_iter()
+        .map(|mv| minimax(&after_move(game, mv), player));
+
+    if game.player_on_turn() == player {
+        values.max().unwrap()
+    } else {
+        values.min().unwrap()
+    }
+}
+
+fn endgame_from_seed(seed: u64, close: bool) -> Game {
+    let mut game = Game::new_seeded(seed);
+    let mut index = seed as usize;
+
+    while !is_endgame(&game) && !game.is_game_over() {
+        if close && game.can_close().is_ok() {
+            assert!(game.close().is_ok());
+            break;
+        }
+
+        let moves: Vec<Move> = game.legal_moves().into_iter()
+            .filter(|&mv| mv != Move::Close)
+            .collect();
+        index += 7;
+        assert!(game.apply(moves[index % moves.len()]).is_ok());
+    }
+
+    game
+}
+
+#[test]
+fn test_solve_not_endgame() {
+    let game = Game::new_seeded(1);
+
+    assert!(!is_endgame(&game));
+    assert_eq!(None, solve(&game));
+}
+
+#[test]
+fn test_solve_last_trick() {
+    let deck = ::schnapsen::generate_deck();
+    let leaves_ace = Card::new(Suit::Leaves, Rank::Ace);
+    let leaves_ten = Card::new(Suit::Leaves, Rank::Ten);
+
+    let wins2: Vec<Card> = deck.iter().cloned()
+        .filter(|card| card.suit() == Suit::Hearts)
+        .chain(Some(Card::new(Suit::Leaves, Rank::King)))
+        .collect();
+    let wins1: Vec<Card> = deck.iter().cloned()
+        .filter(|card| !wins2.contains(card) && *card != leaves_ace
+                && *card != leaves_ten)
+        .collect();
+
+    let game = GameBuilder::new()
+        .hand(PlayerId::Player1, vec![leaves_ten])
+        .hand(PlayerId::Player2, vec![leaves_ace])
+        .wins(PlayerId::Player1, wins1)
+        .wins(PlayerId::Player2, wins2)
+        .trump(Suit::Bells)
+        .build()
+        .unwrap();
+
+    // Player2 wins the last trick; Player1 has more than 33 points.
+    let expected = Solution {best_move: Move::PlayCard(leaves_ten), value: -1};
+    assert_eq!(Some(expected), solve(&game));
+}
+
+#[test]
+fn test_solve_prefers_declaring_win() {
+    let deck = ::schnapsen::generate_deck();
+    let hand1 = vec![Card::new(Suit::Leaves, Rank::Unter),
+                     Card::new(Suit::Leaves, Rank::Ober)];
+    let hand2 = vec![Card::new(Suit::Leaves, Rank::Ace),
+                     Card::new(Suit::Hearts, Rank::Unter)];
+    let wins2 = vec![Card::new(Suit::Hearts, Rank::Ober),
+                     Card::new(Suit::Hearts, Rank::King)];
+    let wins1: Vec<Card> = deck.iter().cloned()
+        .filter(|card| !hand1.contains(card) && !hand2.contains(card)
+                && !wins2.contains(card))
+        .collect();
+
+    let game = GameBuilder::new()
+        .hand(PlayerId::Player1, hand1)
+        .hand(PlayerId::Player2, hand2)
+        .wins(PlayerId::Player1, wins1)
+        .wins(PlayerId::Player2, wins2)
+        .trump(Suit::Acorns)
+        .build()
+        .unwrap();
+
+    let expected = Solution {best_move: Move::DeclareWin, value: 2};
+    assert_eq!(Some(expected), solve(&game));
+}
+
+#[test]
+fn test_solve_matches_minimax() {
+    for seed in 0..12 {
+        let game = endgame_from_seed(seed, seed % 2 == 0);
+        if game.is_game_over() {
+            continue;
+        }
+
+        let solution = solve(&game).unwrap();
+        let player = game.player_on_turn();
+
+        assert_eq!(minimax(&game, player), solution.value);
+        assert_eq!(solution.value,
+                   minimax(&after_move(&game, solution.best_move), player));
+    }
+}
+
+#[test]
+fn test_solve_principal_variation() {
+    let mut game = endgame_from_seed(3, true);
+    let player = game.player_on_turn();
+    let value = solve(&game).unwrap().value;
+
+    while let Some(solution) = solve(&game) {
+        assert!(game.apply(solution.best_move).is_ok());
+    }
+
+    assert_eq!(value, outcome(&game.deal_result().unwrap(), player));
+}
+
+#[test]
+fn test_evaluate_endgame_is_exact() {
+    for seed in 0..5 {
+        let game = endgame_from_seed(seed, seed % 2 == 0);
+        if game.is_game_over() {
+            continue;
+        }
+
+        let player = game.player_on_turn();
+        let solution = solve(&game).unwrap();
+        assert_eq!(solution.value * 100, evaluate(&game, player, 0));
+        assert_eq!(-solution.value * 100, evaluate(&game, player.other(), 3));
+    }
+}
+
+#[test]
+fn test_evaluate_heuristic() {
+    let game = Game::new_seeded(1);
+
+    assert_eq!(0, evaluate(&game, PlayerId::Player1, 0));
+
+    for depth in 1..3 {
+        let value = evaluate(&game, PlayerId::Player1, depth);
+        assert!(value > -300 && value < 300);
+    }
+}
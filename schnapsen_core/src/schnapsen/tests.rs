@@ -73,6 +73,55 @@ fn test_generate_deck() {
     }
 }
 
+#[test]
+fn test_validate_deck_ok() {
+    let deck = generate_deck();
+    assert_eq!(Ok(()), validate_deck(&deck));
+}
+
+#[test]
+fn test_validate_deck_wrong_size() {
+    let mut deck = generate_deck();
+    deck.pop();
+
+    assert_eq!(Err(DeckError::WrongSize(19)), validate_deck(&deck));
+}
+
+#[test]
+fn test_validate_deck_illegal_card() {
+    let mut deck = generate_deck();
+    let illegal_card = Card::new(Suit::Hearts, Rank::Nine);
+    deck[3] = illegal_card;
+
+    assert_eq!(Err(DeckError::IllegalCard(illegal_card)),
+               validate_deck(&deck));
+}
+
+#[test]
+fn test_validate_deck_duplicate_card() {
+    let mut deck = generate_deck();
+    let duplicate_card = deck[2];
+    deck[7] = duplicate_card;
+
+    assert_eq!(Err(DeckError::DuplicateCard(duplicate_card)),
+               validate_deck(&deck));
+}
+
+#[test]
+fn test_generate_shuffled_deck() {
+    let deck = generate_deck();
+    let shuffled_deck = generate_shuffled_deck(&mut seeded_rng(3));
+
+    assert_eq!(deck.len(), shuffled_deck.len());
+
+    for card in deck.iter() {
+        assert!(shuffled_deck.contains(card));
+    }
+
+    assert_eq!(shuffled_deck, generate_shuffled_deck(&mut seeded_rng(3)));
+    assert_eq!(shuffled_deck, generate_seeded_deck(3));
+}
+
 #[test]
 fn test_first_beats_second_same_suit() {
     let card1 = Card {suit: Suit::Leaves, rank: Rank::Ace};
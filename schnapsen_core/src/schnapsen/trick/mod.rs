@@ -0,0 +1,33 @@
+use cards::Card;
+use schnapsen::player::PlayerId;
+use schnapsen::value;
+
+// A completed trick. The stock size is the one at the time the trick was
+// played, before the cards were dealt from it.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Trick {
+    // Counted from 1, including tricks played before the Game was set up.
+    pub number: u32,
+    pub leader: PlayerId,
+    pub lead_card: Card,
+    pub reply_card: Card,
+    pub winner: PlayerId,
+    pub stock_size: u32
+}
+
+impl Trick {
+    pub fn points(&self) -> u32 {
+        value(self.lead_card) + value(self.reply_card)
+    }
+
+    pub fn card_of(&self, player: PlayerId) -> Card {
+        if player == self.leader {
+            self.lead_card
+        } else {
+            self.reply_card
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,14 @@
+use super::*;
+use cards::{Suit, Rank};
+
+#[test]
+fn test_trick_points_and_cards() {
+    let lead_card = Card::new(Suit::Leaves, Rank::Ace);
+    let reply_card = Card::new(Suit::Leaves, Rank::King);
+    let trick = Trick {number: 1, leader: PlayerId::Player2, lead_card,
+                       reply_card, winner: PlayerId::Player2, stock_size: 10};
+
+    assert_eq!(15, trick.points());
+    assert_eq!(lead_card, trick.card_of(PlayerId::Player2));
+    assert_eq!(reply_card, trick.card_of(PlayerId::Player1));
+}
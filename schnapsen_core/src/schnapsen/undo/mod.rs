@@ -0,0 +1,64 @@
+use cards::{Card, Suit};
+use schnapsen::card_set::CardSet;
+use schnapsen::deal_result::DealResult;
+use schnapsen::game_data::PublicGameData;
+use schnapsen::moves::Move;
+use schnapsen::player::Player;
+
+// What a move may change about a player. Marriages and revealed cards are
+// only ever added, so their counts are enough to take them back.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub(super) struct PlayerUndo {
+    hand: CardSet,
+    wins: CardSet,
+    twenties: usize,
+    forty: Option<Suit>,
+    revealed: usize
+}
+
+impl PlayerUndo {
+    pub(super) fn save(player: &Player) -> PlayerUndo {
+        PlayerUndo {hand: player.hand, wins: player.wins,
+                    twenties: player.twenties.len(), forty: player.forty,
+                    revealed: player.revealed.len()}
+    }
+
+    pub(super) fn restore(&self, player: &mut Player) {
+        player.hand = self.hand;
+        player.wins = self.wins;
+        player.twenties.truncate(self.twenties);
+        player.forty = self.forty;
+        player.revealed.truncate(self.revealed);
+    }
+}
+
+// Everything needed to take back one move of a Game.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub(super) struct UndoEntry {
+    pub(super) mv: Move,
+    pub(super) player1: PlayerUndo,
+    pub(super) player2: PlayerUndo,
+    pub(super) public_data: PublicGameData,
+    pub(super) result: Option<DealResult>,
+    // The size of the stock, its bottom card and its two top cards, which
+    // are the only ones a move can exchange or deal.
+    pub(super) stock_size: usize,
+    pub(super) trump_card: Option<Card>,
+    pub(super) stock_top: [Option<Card>; 2],
+    pub(super) tricks: usize,
+    // The number of events ever pushed, drained or not.
+    pub(super) event_count: usize,
+    pub(super) hash: u64
+}
+
+impl UndoEntry {
+    pub(super) fn restore_stock(&self, stock: &mut Vec<Card>) {
+        if stock.len() < self.stock_size {
+            stock.extend(self.stock_top.iter().filter_map(|&card| card));
+        }
+
+        if let Some(trump_card) = self.trump_card {
+            stock[0] = trump_card;
+        }
+    }
+}
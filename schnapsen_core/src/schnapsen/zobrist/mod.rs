@@ -0,0 +1,144 @@
+use cards::{Card, Suit};
+use schnapsen::game_data::{Closing, PublicGameData};
+use schnapsen::player::{Player, PlayerId};
+
+// Zobrist keys for the features of a position. Every key is derived from the
+// feature alone with SplitMix64, so the hashes are the same across runs,
+// platforms and versions as long as the feature numbering below is kept.
+
+const HAND: u64 = 1;
+const WINS: u64 = 2;
+const STOCK: u64 = 3;
+const TRICK: u64 = 4;
+const TRUMP: u64 = 5;
+const TWENTY: u64 = 6;
+const FORTY: u64 = 7;
+const LEAD: u64 = 8;
+const CLOSED: u64 = 9;
+const CLOSER: u64 = 10;
+const CLOSING_STOCK_SIZE: u64 = 11;
+const CLOSING_OPPONENT_SCORE: u64 = 12;
+const CLOSING_OPPONENT_HAS_TRICK: u64 = 13;
+const WINNER: u64 = 14;
+
+pub fn hand(player: PlayerId, card: Card) -> u64 {
+    key(HAND, player_index(player) * 32 + card_index(card))
+}
+
+pub fn wins(player: PlayerId, card: Card) -> u64 {
+    key(WINS, player_index(player) * 32 + card_index(card))
+}
+
+// Position 0 is the bottom of the stock, i.e. the trump card.
+pub fn stock(position: usize, card: Card) -> u64 {
+    key(STOCK, position as u64 * 32 + card_index(card))
+}
+
+pub fn trick(card: Card) -> u64 {
+    key(TRICK, card_index(card))
+}
+
+pub fn trump(suit: Suit) -> u64 {
+    key(TRUMP, suit as u64)
+}
+
+pub fn twenty(player: PlayerId, suit: Suit) -> u64 {
+    key(TWENTY, player_index(player) * 4 + suit as u64)
+}
+
+pub fn forty(player: PlayerId) -> u64 {
+    key(FORTY, player_index(player))
+}
+
+pub fn lead(player: PlayerId) -> u64 {
+    key(LEAD, player_index(player))
+}
+
+pub fn closed() -> u64 {
+    key(CLOSED, 0)
+}
+
+pub fn closing(closing: &Closing) -> u64 {
+    key(CLOSER, player_index(closing.closer))
+        ^ key(CLOSING_STOCK_SIZE, u64::from(closing.stock_size))
+        ^ key(CLOSING_OPPONENT_SCORE, u64::from(closing.opponent_score))
+        ^ key(CLOSING_OPPONENT_HAS_TRICK, closing.opponent_has_trick as u64)
+}
+
+pub fn winner(player: PlayerId) -> u64 {
+    key(WINNER, player_index(player))
+}
+
+pub fn player(player_id: PlayerId, player: &Player) -> u64 {
+    let mut hash = 0;
+
+    for card in player.hand.iter() {
+        hash ^= hand(player_id, card);
+    }
+
+    for card in player.wins.iter() {
+        hash ^= wins(player_id, card);
+    }
+
+    for &suit in &player.twenties {
+        hash ^= twenty(player_id, suit);
+    }
+
+    if player.forty.is_some() {
+        hash ^= forty(player_id);
+    }
+
+    hash
+}
+
+pub fn public_data(data: &PublicGameData) -> u64 {
+    let mut hash = trump(data.trump) ^ lead(data.player_on_lead);
+
+    if data.closed {
+        hash ^= closed();
+    }
+
+    if let Some(ref data_closing) = data.closing {
+        hash ^= closing(data_closing);
+    }
+
+    if let Some(player) = data.winner {
+        hash ^= winner(player);
+    }
+
+    if let Some(card) = data.first_card_in_trick {
+        hash ^= trick(card);
+    }
+
+    hash
+}
+
+pub fn position(stock_cards: &[Card], player1: &Player, player2: &Player,
+                data: &PublicGameData) -> u64 {
+    let stock_hash = stock_cards.iter().enumerate()
+        .fold(0, |hash, (position, &card)| hash ^ stock(position, card));
+
+    stock_hash ^ player(PlayerId::Player1, player1)
+        ^ player(PlayerId::Player2, player2) ^ public_data(data)
+}
+
+fn player_index(player: PlayerId) -> u64 {
+    match player {
+        PlayerId::Player1 => 0,
+        PlayerId::Player2 => 1
+    }
+}
+
+fn card_index(card: Card) -> u64 {
+    card.suit() as u64 * 8 + card.rank() as u64
+}
+
+fn key(kind: u64, index: u64) -> u64 {
+    let mut z = (kind << 32 | index).wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+#[cfg(test)]
+mod tests;
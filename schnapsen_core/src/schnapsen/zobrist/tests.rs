@@ -0,0 +1,42 @@
+use super::*;
+use cards::Rank;
+use schnapsen::generate_deck;
+
+#[test]
+fn test_keys_are_stable() {
+    // Changing these values invalidates stored hashes.
+    assert_eq!(0xE220_A839_7B1D_CDAF, key(0, 0));
+    assert_eq!(key(HAND, 3), hand(PlayerId::Player1,
+                                  Card::new(Suit::Hearts, Rank::Ten)));
+}
+
+#[test]
+fn test_keys_are_distinct() {
+    let mut keys = Vec::new();
+
+    for &player_id in [PlayerId::Player1, PlayerId::Player2].iter() {
+        for card in generate_deck() {
+            keys.push(hand(player_id, card));
+            keys.push(wins(player_id, card));
+        }
+
+        for &suit in ::schnapsen::SUITS.iter() {
+            keys.push(twenty(player_id, suit));
+        }
+
+        keys.push(forty(player_id));
+        keys.push(lead(player_id));
+        keys.push(winner(player_id));
+    }
+
+    for (position, card) in generate_deck().into_iter().enumerate() {
+        keys.push(stock(position, card));
+        keys.push(trick(card));
+    }
+
+    keys.push(closed());
+
+    for (index, key) in keys.iter().enumerate() {
+        assert!(!keys[..index].contains(key));
+    }
+}
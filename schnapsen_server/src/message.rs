@@ -1,5 +1,5 @@
 use schnapsen_core::cards::{Card, Suit};
-use schnapsen_core::schnapsen;
+use schnapsen_core::schnapsen::{self, Move};
 
 #[derive(Debug, Deserialize, Serialize)]
 pub enum RequestData {
@@ -13,6 +13,21 @@ pub enum RequestData {
     PlayCard(Card)
 }
 
+impl From<RequestData> for Move {
+    fn from(data: RequestData) -> Move {
+        match data {
+            RequestData::Close => Move::Close,
+            RequestData::ExchangeTrump => Move::ExchangeTrump,
+            RequestData::PlayTwenty(card) => Move::PlayTwenty(card),
+            RequestData::TwentyDeclareWin(suit) => Move::DeclareTwentyWin(suit),
+            RequestData::PlayForty(card) => Move::PlayForty(card),
+            RequestData::FortyDeclareWin => Move::DeclareFortyWin,
+            RequestData::Declare => Move::DeclareWin,
+            RequestData::PlayCard(card) => Move::PlayCard(card)
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct Request {
     pub id: u32,
@@ -40,4 +55,13 @@ mod tests {
         let deserialized: Request = ::serde_json::from_str(&serialized).unwrap();
         println!("deserialized = {:?}", deserialized);
     }
+
+    #[test]
+    fn test_request_data_to_move() {
+        let mv: Move = RequestData::TwentyDeclareWin(Suit::Leaves).into();
+        assert_eq!(Move::DeclareTwentyWin(Suit::Leaves), mv);
+
+        let mv: Move = RequestData::Declare.into();
+        assert_eq!(Move::DeclareWin, mv);
+    }
 }